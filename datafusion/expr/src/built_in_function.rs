@@ -25,6 +25,7 @@ use std::sync::{Arc, OnceLock};
 
 use crate::nullif::SUPPORTED_NULLIF_TYPES;
 use crate::signature::TIMEZONE_WILDCARD;
+use crate::type_coercion::aggregates::sum_return_type;
 use crate::type_coercion::binary::get_wider_type;
 use crate::type_coercion::functions::data_types;
 use crate::{
@@ -131,8 +132,14 @@ pub enum BuiltinScalarFunction {
     ArrayAppend,
     /// array_sort
     ArraySort,
+    /// array_compact
+    ArrayCompact,
     /// array_concat
     ArrayConcat,
+    /// array_concat_distinct
+    ArrayConcatDistinct,
+    /// array_concat_flat
+    ArrayConcatFlat,
     /// array_has
     ArrayHas,
     /// array_has_all
@@ -145,6 +152,8 @@ pub enum BuiltinScalarFunction {
     ArrayPopBack,
     /// array_dims
     ArrayDims,
+    /// array_distance
+    ArrayDistance,
     /// array_distinct
     ArrayDistinct,
     /// array_element
@@ -153,10 +162,16 @@ pub enum BuiltinScalarFunction {
     ArrayEmpty,
     /// array_length
     ArrayLength,
+    /// array_max
+    ArrayMax,
+    /// array_min
+    ArrayMin,
     /// array_ndims
     ArrayNdims,
     /// array_position
     ArrayPosition,
+    /// array_position_from_end
+    ArrayPositionFromEnd,
     /// array_positions
     ArrayPositions,
     /// array_prepend
@@ -175,8 +190,14 @@ pub enum BuiltinScalarFunction {
     ArrayReplaceN,
     /// array_replace_all
     ArrayReplaceAll,
+    /// array_reverse
+    ArrayReverse,
     /// array_slice
     ArraySlice,
+    /// array_sublist
+    ArraySublist,
+    /// array_sum
+    ArraySum,
     /// array_to_string
     ArrayToString,
     /// array_intersect
@@ -402,20 +423,27 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::Trunc => Volatility::Immutable,
             BuiltinScalarFunction::ArrayAppend => Volatility::Immutable,
             BuiltinScalarFunction::ArraySort => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayCompact => Volatility::Immutable,
             BuiltinScalarFunction::ArrayConcat => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayConcatDistinct => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayConcatFlat => Volatility::Immutable,
             BuiltinScalarFunction::ArrayEmpty => Volatility::Immutable,
             BuiltinScalarFunction::ArrayHasAll => Volatility::Immutable,
             BuiltinScalarFunction::ArrayHasAny => Volatility::Immutable,
             BuiltinScalarFunction::ArrayHas => Volatility::Immutable,
             BuiltinScalarFunction::ArrayDims => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayDistance => Volatility::Immutable,
             BuiltinScalarFunction::ArrayDistinct => Volatility::Immutable,
             BuiltinScalarFunction::ArrayElement => Volatility::Immutable,
             BuiltinScalarFunction::ArrayExcept => Volatility::Immutable,
             BuiltinScalarFunction::ArrayLength => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayMax => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayMin => Volatility::Immutable,
             BuiltinScalarFunction::ArrayNdims => Volatility::Immutable,
             BuiltinScalarFunction::ArrayPopFront => Volatility::Immutable,
             BuiltinScalarFunction::ArrayPopBack => Volatility::Immutable,
             BuiltinScalarFunction::ArrayPosition => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayPositionFromEnd => Volatility::Immutable,
             BuiltinScalarFunction::ArrayPositions => Volatility::Immutable,
             BuiltinScalarFunction::ArrayPrepend => Volatility::Immutable,
             BuiltinScalarFunction::ArrayRepeat => Volatility::Immutable,
@@ -425,8 +453,11 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::ArrayReplace => Volatility::Immutable,
             BuiltinScalarFunction::ArrayReplaceN => Volatility::Immutable,
             BuiltinScalarFunction::ArrayReplaceAll => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayReverse => Volatility::Immutable,
             BuiltinScalarFunction::Flatten => Volatility::Immutable,
             BuiltinScalarFunction::ArraySlice => Volatility::Immutable,
+            BuiltinScalarFunction::ArraySublist => Volatility::Immutable,
+            BuiltinScalarFunction::ArraySum => Volatility::Immutable,
             BuiltinScalarFunction::ArrayToString => Volatility::Immutable,
             BuiltinScalarFunction::ArrayIntersect => Volatility::Immutable,
             BuiltinScalarFunction::ArrayUnion => Volatility::Immutable,
@@ -550,36 +581,78 @@ impl BuiltinScalarFunction {
                 Ok(data_type)
             }
             BuiltinScalarFunction::ArrayAppend => Ok(input_expr_types[0].clone()),
+            BuiltinScalarFunction::ArrayCompact => Ok(input_expr_types[0].clone()),
             BuiltinScalarFunction::ArraySort => Ok(input_expr_types[0].clone()),
-            BuiltinScalarFunction::ArrayConcat => {
+            BuiltinScalarFunction::ArrayConcatFlat => {
+                // Each argument loses one level of nesting, then is concatenated
+                let flat_types = input_expr_types
+                    .iter()
+                    .map(|input_expr_type| match input_expr_type {
+                        List(field) | LargeList(field) => match field.data_type() {
+                            List(inner) | LargeList(inner) => {
+                                Ok(match input_expr_type {
+                                    LargeList(_) => LargeList(inner.clone()),
+                                    _ => List(inner.clone()),
+                                })
+                            }
+                            Null => Ok(input_expr_type.clone()),
+                            _ => plan_err!(
+                                "The {self} function can only accept lists of lists, got {input_expr_type}"
+                            ),
+                        },
+                        _ => plan_err!(
+                            "The {self} function can only accept lists of lists, got {input_expr_type}"
+                        ),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                BuiltinScalarFunction::ArrayConcat.return_type(&flat_types)
+            }
+            BuiltinScalarFunction::ArrayConcat
+            | BuiltinScalarFunction::ArrayConcatDistinct => {
+                // The outermost lists are promoted to a `LargeList` if any argument is a
+                // `LargeList`, otherwise to a `List`, including for `FixedSizeList` arguments
+                let mut is_large = false;
                 let mut expr_type = Null;
                 let mut max_dims = 0;
+                let mut first_field = None;
                 for input_expr_type in input_expr_types {
-                    match input_expr_type {
-                        List(field) => {
-                            if !field.data_type().equals_datatype(&Null) {
-                                let dims = self.return_dimension(input_expr_type);
-                                expr_type = match max_dims.cmp(&dims) {
-                                    Ordering::Greater => expr_type,
-                                    Ordering::Equal => {
-                                        get_wider_type(&expr_type, input_expr_type)?
-                                    }
-                                    Ordering::Less => {
-                                        max_dims = dims;
-                                        input_expr_type.clone()
-                                    }
-                                };
-                            }
+                    let field = match input_expr_type {
+                        List(field) | FixedSizeList(field, _) => field,
+                        LargeList(field) => {
+                            is_large = true;
+                            field
                         }
                         _ => {
                             return plan_err!(
                                 "The {self} function can only accept list as the args."
                             )
                         }
+                    };
+                    first_field.get_or_insert_with(|| field.clone());
+                    if !field.data_type().equals_datatype(&Null) {
+                        let list_type = List(field.clone());
+                        let dims = self.return_dimension(&list_type);
+                        expr_type = match max_dims.cmp(&dims) {
+                            Ordering::Greater => expr_type,
+                            Ordering::Equal => get_wider_type(&expr_type, &list_type)?,
+                            Ordering::Less => {
+                                max_dims = dims;
+                                list_type
+                            }
+                        };
                     }
                 }
 
-                Ok(expr_type)
+                // Null typed arguments are skipped, if all of them are the first one is kept
+                if expr_type == Null {
+                    if let Some(field) = first_field {
+                        expr_type = List(field);
+                    }
+                }
+                match expr_type {
+                    List(field) if is_large => Ok(LargeList(field)),
+                    expr_type => Ok(expr_type),
+                }
             }
             BuiltinScalarFunction::ArrayHasAll
             | BuiltinScalarFunction::ArrayHasAny
@@ -588,44 +661,213 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::ArrayDims => {
                 Ok(List(Arc::new(Field::new("item", UInt64, true))))
             }
+            BuiltinScalarFunction::ArrayDistance => {
+                for data_type in input_expr_types {
+                    match data_type {
+                        List(field) | LargeList(field)
+                            if field.data_type().is_numeric()
+                                || field.data_type() == &Null => {}
+                        data_type => {
+                            return plan_err!(
+                                "The {self} function can only accept lists of numbers, got {data_type}"
+                            )
+                        }
+                    }
+                }
+                Ok(Float64)
+            }
             BuiltinScalarFunction::ArrayDistinct => Ok(input_expr_types[0].clone()),
-            BuiltinScalarFunction::ArrayElement => match &input_expr_types[0] {
-                List(field) => Ok(field.data_type().clone()),
-                _ => plan_err!(
-                    "The {self} function can only accept list as the first argument"
-                ),
-            },
+            BuiltinScalarFunction::ArrayElement => {
+                // Indexes are not truncated, a float or decimal index is an error
+                match &input_expr_types[1] {
+                    Int64 | Null => {}
+                    List(field) if matches!(field.data_type(), Int64 | Null) => {}
+                    data_type => {
+                        return plan_err!(
+                            "The {self} function expects an integer index of type Int64 or a list of them, got {data_type}"
+                        );
+                    }
+                }
+                match (&input_expr_types[0], &input_expr_types[1]) {
+                    // A list of indexes picks a list of elements
+                    (List(field) | FixedSizeList(field, _), List(_)) => Ok(List(Arc::new(Field::new(
+                        "item",
+                        field.data_type().clone(),
+                        true,
+                    )))),
+                    (LargeList(field), List(_)) => Ok(LargeList(Arc::new(Field::new(
+                        "item",
+                        field.data_type().clone(),
+                        true,
+                    )))),
+                    (List(field) | LargeList(field) | FixedSizeList(field, _), _) => {
+                        Ok(field.data_type().clone())
+                    }
+                    (data_type, _) => plan_err!(
+                        "The {self} function can only accept list as the first argument, got {data_type}"
+                    ),
+                }
+            }
             BuiltinScalarFunction::ArrayLength => Ok(UInt64),
+            BuiltinScalarFunction::ArrayMax | BuiltinScalarFunction::ArrayMin => {
+                match &input_expr_types[0] {
+                    List(field) | LargeList(field) | FixedSizeList(field, _) => {
+                        // The element types the MIN and MAX aggregates can compare,
+                        // dictionaries are compared by their values
+                        let value_type = match field.data_type() {
+                            Dictionary(_, value_type) => value_type.as_ref(),
+                            data_type => data_type,
+                        };
+                        match value_type {
+                            Null | Boolean | Utf8 | LargeUtf8 | Binary | LargeBinary
+                            | Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32
+                            | UInt64 | Float32 | Float64 | Decimal128(_, _)
+                            | Decimal256(_, _) | Timestamp(_, _) | Date32 | Date64
+                            | Time32(Second | Millisecond)
+                            | Time64(Microsecond | Nanosecond) => {
+                                Ok(field.data_type().clone())
+                            }
+                            data_type if data_type.is_nested() => plan_err!(
+                                "The {self} function can only accept lists of non-nested elements, got a list of {data_type}"
+                            ),
+                            _ => plan_err!(
+                                "The {self} function can not compare elements of type {}",
+                                field.data_type()
+                            ),
+                        }
+                    }
+                    Null => Ok(Null),
+                    data_type => plan_err!(
+                        "The {self} function can only accept lists, got {data_type}"
+                    ),
+                }
+            }
             BuiltinScalarFunction::ArrayNdims => Ok(UInt64),
             BuiltinScalarFunction::ArrayPopFront => Ok(input_expr_types[0].clone()),
             BuiltinScalarFunction::ArrayPopBack => Ok(input_expr_types[0].clone()),
             BuiltinScalarFunction::ArrayPosition => Ok(UInt64),
+            BuiltinScalarFunction::ArrayPositionFromEnd => Ok(UInt64),
             BuiltinScalarFunction::ArrayPositions => {
                 Ok(List(Arc::new(Field::new("item", UInt64, true))))
             }
             BuiltinScalarFunction::ArrayPrepend => Ok(input_expr_types[1].clone()),
-            BuiltinScalarFunction::ArrayRepeat => Ok(List(Arc::new(Field::new(
-                "item",
-                input_expr_types[0].clone(),
-                true,
-            )))),
+            BuiltinScalarFunction::ArrayRepeat => {
+                let field =
+                    Arc::new(Field::new("item", input_expr_types[0].clone(), true));
+                match &input_expr_types[0] {
+                    LargeList(_) => Ok(LargeList(field)),
+                    // A fixed size list is repeated as a list of its elements
+                    FixedSizeList(field, _) => Ok(List(Arc::new(Field::new(
+                        "item",
+                        List(field.clone()),
+                        true,
+                    )))),
+                    _ => Ok(List(field)),
+                }
+            }
             BuiltinScalarFunction::ArrayRemove => Ok(input_expr_types[0].clone()),
             BuiltinScalarFunction::ArrayRemoveN => Ok(input_expr_types[0].clone()),
             BuiltinScalarFunction::ArrayRemoveAll => Ok(input_expr_types[0].clone()),
             BuiltinScalarFunction::ArrayReplace => Ok(input_expr_types[0].clone()),
             BuiltinScalarFunction::ArrayReplaceN => Ok(input_expr_types[0].clone()),
             BuiltinScalarFunction::ArrayReplaceAll => Ok(input_expr_types[0].clone()),
-            BuiltinScalarFunction::ArraySlice => Ok(input_expr_types[0].clone()),
+            BuiltinScalarFunction::ArrayReverse => Ok(input_expr_types[0].clone()),
+            BuiltinScalarFunction::ArraySum => match &input_expr_types[0] {
+                List(field) | LargeList(field) | FixedSizeList(field, _) => {
+                    // Integers and floats are summed in their widest type, like SUM
+                    match field.data_type() {
+                        Int8 | Int16 | Int32 | Int64 | Null => Ok(Int64),
+                        UInt8 | UInt16 | UInt32 | UInt64 => Ok(UInt64),
+                        Float16 | Float32 | Float64 => Ok(Float64),
+                        data_type @ (Decimal128(_, _) | Decimal256(_, _)) => {
+                            sum_return_type(data_type)
+                        }
+                        data_type => plan_err!(
+                            "The {self} function can only accept lists of numbers, got a list of {data_type}"
+                        ),
+                    }
+                }
+                Null => Ok(Null),
+                data_type => plan_err!(
+                    "The {self} function can only accept lists of numbers, got {data_type}"
+                ),
+            },
+            BuiltinScalarFunction::ArraySlice => {
+                // The arguments are positional: (array, begin[, end[, null_on_invalid]])
+                for data_type in input_expr_types.iter().skip(1).take(2) {
+                    if !matches!(data_type, Int64 | Null) {
+                        return plan_err!(
+                            "The {self} function expects integer begin and end indexes of type Int64, got {data_type}"
+                        );
+                    }
+                }
+                if let Some(data_type) = input_expr_types.get(3) {
+                    if !matches!(data_type, Boolean | Null) {
+                        return plan_err!(
+                            "The {self} function expects a Boolean null_on_invalid as the fourth argument, got {data_type}"
+                        );
+                    }
+                }
+                match &input_expr_types[0] {
+                    List(_) | LargeList(_) => Ok(input_expr_types[0].clone()),
+                    data_type => plan_err!(
+                        "The {self} function can only accept list as the first argument, got {data_type}"
+                    ),
+                }
+            }
+            BuiltinScalarFunction::ArraySublist => {
+                // The index and length are cast to Int64 by the type coercion
+                for data_type in &input_expr_types[1..] {
+                    if !data_type.is_integer() && data_type != &Null {
+                        return plan_err!(
+                            "The {self} function expects an integer index and length, got {data_type}"
+                        );
+                    }
+                }
+                match &input_expr_types[0] {
+                    List(_) | LargeList(_) => Ok(input_expr_types[0].clone()),
+                    data_type => plan_err!(
+                        "The {self} function can only accept list as the first argument, got {data_type}"
+                    ),
+                }
+            }
             BuiltinScalarFunction::ArrayToString => Ok(Utf8),
-            BuiltinScalarFunction::ArrayUnion | BuiltinScalarFunction::ArrayIntersect => {
+            BuiltinScalarFunction::ArrayUnion => {
                 match (input_expr_types[0].clone(), input_expr_types[1].clone()) {
                     (DataType::Null, dt) => Ok(dt),
                     (dt, DataType::Null) => Ok(dt),
+                    // An empty array has a null element type, the union takes the other type
+                    (List(field) | LargeList(field), dt)
+                        if field.data_type() == &DataType::Null =>
+                    {
+                        Ok(dt)
+                    }
                     (dt, _) => Ok(dt),
                 }
             }
+            BuiltinScalarFunction::ArrayIntersect => {
+                if input_expr_types.iter().any(|dt| matches!(dt, List(_)))
+                    && input_expr_types.iter().any(|dt| matches!(dt, LargeList(_)))
+                {
+                    return plan_err!(
+                        "The {self} function does not support mixing List and LargeList arguments, got {input_expr_types:?}"
+                    );
+                }
+                Ok(input_expr_types
+                    .iter()
+                    .find(|dt| **dt != DataType::Null)
+                    .unwrap_or(&input_expr_types[0])
+                    .clone())
+            }
             BuiltinScalarFunction::Range => {
-                Ok(List(Arc::new(Field::new("item", Int64, true))))
+                // Dates and timestamps are stepped by an interval and keep their type
+                let item_type = match input_expr_types {
+                    [data_type @ (Float64 | Date32 | Timestamp(_, _)), _, _] => {
+                        data_type.clone()
+                    }
+                    _ => Int64,
+                };
+                Ok(List(Arc::new(Field::new("item", item_type, true))))
             }
             BuiltinScalarFunction::ArrayExcept => {
                 match (input_expr_types[0].clone(), input_expr_types[1].clone()) {
@@ -921,13 +1163,21 @@ impl BuiltinScalarFunction {
             }
             BuiltinScalarFunction::ArrayPopFront => Signature::any(1, self.volatility()),
             BuiltinScalarFunction::ArrayPopBack => Signature::any(1, self.volatility()),
-            BuiltinScalarFunction::ArrayConcat => {
+            BuiltinScalarFunction::ArrayCompact => Signature::any(1, self.volatility()),
+            BuiltinScalarFunction::ArrayConcat
+            | BuiltinScalarFunction::ArrayConcatDistinct
+            | BuiltinScalarFunction::ArrayConcatFlat => {
                 Signature::variadic_any(self.volatility())
             }
             BuiltinScalarFunction::ArrayDims => Signature::any(1, self.volatility()),
+            BuiltinScalarFunction::ArrayDistance => Signature::any(2, self.volatility()),
             BuiltinScalarFunction::ArrayEmpty => Signature::any(1, self.volatility()),
-            BuiltinScalarFunction::ArrayElement => Signature::any(2, self.volatility()),
-            BuiltinScalarFunction::ArrayExcept => Signature::any(2, self.volatility()),
+            BuiltinScalarFunction::ArrayElement => {
+                Signature::one_of(vec![Any(2), Any(3)], self.volatility())
+            }
+            BuiltinScalarFunction::ArrayExcept => {
+                Signature::variadic_any(self.volatility())
+            }
             BuiltinScalarFunction::Flatten => Signature::any(1, self.volatility()),
             BuiltinScalarFunction::ArrayHasAll
             | BuiltinScalarFunction::ArrayHasAny
@@ -935,11 +1185,18 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::ArrayLength => {
                 Signature::variadic_any(self.volatility())
             }
+            BuiltinScalarFunction::ArrayMax => Signature::any(1, self.volatility()),
+            BuiltinScalarFunction::ArrayMin => Signature::any(1, self.volatility()),
             BuiltinScalarFunction::ArrayNdims => Signature::any(1, self.volatility()),
-            BuiltinScalarFunction::ArrayDistinct => Signature::any(1, self.volatility()),
+            BuiltinScalarFunction::ArrayDistinct => {
+                Signature::one_of(vec![Any(1), Any(2)], self.volatility())
+            }
             BuiltinScalarFunction::ArrayPosition => {
                 Signature::variadic_any(self.volatility())
             }
+            BuiltinScalarFunction::ArrayPositionFromEnd => {
+                Signature::any(2, self.volatility())
+            }
             BuiltinScalarFunction::ArrayPositions => Signature::any(2, self.volatility()),
             BuiltinScalarFunction::ArrayPrepend => Signature::any(2, self.volatility()),
             BuiltinScalarFunction::ArrayRepeat => Signature::any(2, self.volatility()),
@@ -951,11 +1208,18 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::ArrayReplaceAll => {
                 Signature::any(3, self.volatility())
             }
-            BuiltinScalarFunction::ArraySlice => Signature::any(3, self.volatility()),
+            BuiltinScalarFunction::ArrayReverse => Signature::any(1, self.volatility()),
+            BuiltinScalarFunction::ArraySum => Signature::any(1, self.volatility()),
+            BuiltinScalarFunction::ArraySlice => {
+                Signature::one_of(vec![Any(2), Any(3), Any(4)], self.volatility())
+            }
+            BuiltinScalarFunction::ArraySublist => Signature::any(3, self.volatility()),
             BuiltinScalarFunction::ArrayToString => {
                 Signature::variadic_any(self.volatility())
             }
-            BuiltinScalarFunction::ArrayIntersect => Signature::any(2, self.volatility()),
+            BuiltinScalarFunction::ArrayIntersect => {
+                Signature::variadic_any(self.volatility())
+            }
             BuiltinScalarFunction::ArrayUnion => Signature::any(2, self.volatility()),
             BuiltinScalarFunction::Cardinality => Signature::any(1, self.volatility()),
             BuiltinScalarFunction::MakeArray => {
@@ -967,6 +1231,18 @@ impl BuiltinScalarFunction {
                     Exact(vec![Int64]),
                     Exact(vec![Int64, Int64]),
                     Exact(vec![Int64, Int64, Int64]),
+                    Exact(vec![Float64, Float64, Float64]),
+                    Exact(vec![Date32, Date32, Interval(MonthDayNano)]),
+                    Exact(vec![
+                        Timestamp(Nanosecond, None),
+                        Timestamp(Nanosecond, None),
+                        Interval(MonthDayNano),
+                    ]),
+                    Exact(vec![
+                        Timestamp(Nanosecond, Some(TIMEZONE_WILDCARD.into())),
+                        Timestamp(Nanosecond, Some(TIMEZONE_WILDCARD.into())),
+                        Interval(MonthDayNano),
+                    ]),
                 ],
                 self.volatility(),
             ),
@@ -1567,10 +1843,18 @@ impl BuiltinScalarFunction {
                 "list_push_back",
             ],
             BuiltinScalarFunction::ArraySort => &["array_sort", "list_sort"],
+            BuiltinScalarFunction::ArrayCompact => &["array_compact", "list_compact"],
             BuiltinScalarFunction::ArrayConcat => {
                 &["array_concat", "array_cat", "list_concat", "list_cat"]
             }
+            BuiltinScalarFunction::ArrayConcatDistinct => {
+                &["array_concat_distinct", "list_concat_distinct"]
+            }
+            BuiltinScalarFunction::ArrayConcatFlat => {
+                &["array_concat_flat", "list_concat_flat"]
+            }
             BuiltinScalarFunction::ArrayDims => &["array_dims", "list_dims"],
+            BuiltinScalarFunction::ArrayDistance => &["array_distance", "list_distance"],
             BuiltinScalarFunction::ArrayDistinct => &["array_distinct", "list_distinct"],
             BuiltinScalarFunction::ArrayEmpty => &["empty"],
             BuiltinScalarFunction::ArrayElement => &[
@@ -1587,6 +1871,8 @@ impl BuiltinScalarFunction {
                 &["array_has", "list_has", "array_contains", "list_contains"]
             }
             BuiltinScalarFunction::ArrayLength => &["array_length", "list_length"],
+            BuiltinScalarFunction::ArrayMax => &["array_max", "list_max"],
+            BuiltinScalarFunction::ArrayMin => &["array_min", "list_min"],
             BuiltinScalarFunction::ArrayNdims => &["array_ndims", "list_ndims"],
             BuiltinScalarFunction::ArrayPopFront => {
                 &["array_pop_front", "list_pop_front"]
@@ -1598,6 +1884,9 @@ impl BuiltinScalarFunction {
                 "array_indexof",
                 "list_indexof",
             ],
+            BuiltinScalarFunction::ArrayPositionFromEnd => {
+                &["array_position_from_end", "list_position_from_end"]
+            }
             BuiltinScalarFunction::ArrayPositions => {
                 &["array_positions", "list_positions"]
             }
@@ -1620,7 +1909,10 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::ArrayReplaceAll => {
                 &["array_replace_all", "list_replace_all"]
             }
+            BuiltinScalarFunction::ArrayReverse => &["array_reverse", "list_reverse"],
             BuiltinScalarFunction::ArraySlice => &["array_slice", "list_slice"],
+            BuiltinScalarFunction::ArraySublist => &["array_sublist", "list_sublist"],
+            BuiltinScalarFunction::ArraySum => &["array_sum", "list_sum"],
             BuiltinScalarFunction::ArrayToString => &[
                 "array_to_string",
                 "list_to_string",
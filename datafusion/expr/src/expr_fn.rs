@@ -599,7 +599,24 @@ scalar_expr!(
     "returns the array without the first element."
 );
 
+scalar_expr!(
+    ArrayCompact,
+    array_compact,
+    array,
+    "returns the array without its null elements."
+);
+
 nary_scalar_expr!(ArrayConcat, array_concat, "concatenates arrays.");
+nary_scalar_expr!(
+    ArrayConcatDistinct,
+    array_concat_distinct,
+    "concatenates arrays and removes duplicates, keeping the first occurrence."
+);
+nary_scalar_expr!(
+    ArrayConcatFlat,
+    array_concat_flat,
+    "flattens one level of each array of arrays and concatenates the results."
+);
 scalar_expr!(
     ArrayHas,
     array_has,
@@ -636,6 +653,12 @@ scalar_expr!(
     array,
     "returns an array of the array's dimensions."
 );
+scalar_expr!(
+    ArrayDistance,
+    array_distance,
+    array1 array2,
+    "returns the Euclidean distance between two numeric arrays."
+);
 scalar_expr!(
     ArrayElement,
     array_element,
@@ -654,6 +677,18 @@ scalar_expr!(
     array dimension,
     "returns the length of the array dimension."
 );
+scalar_expr!(
+    ArrayMax,
+    array_max,
+    array,
+    "returns the maximum non-null element of the array."
+);
+scalar_expr!(
+    ArrayMin,
+    array_min,
+    array,
+    "returns the minimum non-null element of the array."
+);
 scalar_expr!(
     ArrayNdims,
     array_ndims,
@@ -672,6 +707,12 @@ scalar_expr!(
     array element index,
     "searches for an element in the array, returns first occurrence."
 );
+scalar_expr!(
+    ArrayPositionFromEnd,
+    array_position_from_end,
+    array element,
+    "searches for an element in the array, returns last occurrence."
+);
 scalar_expr!(
     ArrayPositions,
     array_positions,
@@ -726,12 +767,30 @@ scalar_expr!(
     array from to,
     "replaces all occurrences of the specified element with another specified element."
 );
+scalar_expr!(
+    ArrayReverse,
+    array_reverse,
+    array,
+    "reverses the order of elements in the array."
+);
 scalar_expr!(
     ArraySlice,
     array_slice,
     array offset length,
     "returns a slice of the array."
 );
+scalar_expr!(
+    ArraySublist,
+    array_sublist,
+    array index length,
+    "returns up to `length` elements of the array starting at `index`."
+);
+scalar_expr!(
+    ArraySum,
+    array_sum,
+    array,
+    "returns the sum of the non-null elements of the array."
+);
 scalar_expr!(
     ArrayToString,
     array_to_string,
@@ -1195,10 +1254,20 @@ mod test {
         test_scalar_expr!(ArraySort, array_sort, array, desc, null_first);
         test_scalar_expr!(ArrayPopFront, array_pop_front, array);
         test_scalar_expr!(ArrayPopBack, array_pop_back, array);
+        test_scalar_expr!(ArrayCompact, array_compact, array);
         test_unary_scalar_expr!(ArrayDims, array_dims);
+        test_scalar_expr!(ArrayDistance, array_distance, array1, array2);
         test_scalar_expr!(ArrayLength, array_length, array, dimension);
+        test_scalar_expr!(ArrayMax, array_max, array);
+        test_scalar_expr!(ArrayMin, array_min, array);
         test_unary_scalar_expr!(ArrayNdims, array_ndims);
         test_scalar_expr!(ArrayPosition, array_position, array, element, index);
+        test_scalar_expr!(
+            ArrayPositionFromEnd,
+            array_position_from_end,
+            array,
+            element
+        );
         test_scalar_expr!(ArrayPositions, array_positions, array, element);
         test_scalar_expr!(ArrayPrepend, array_prepend, array, element);
         test_scalar_expr!(ArrayRepeat, array_repeat, element, count);
@@ -1208,6 +1277,8 @@ mod test {
         test_scalar_expr!(ArrayReplace, array_replace, array, from, to);
         test_scalar_expr!(ArrayReplaceN, array_replace_n, array, from, to, max);
         test_scalar_expr!(ArrayReplaceAll, array_replace_all, array, from, to);
+        test_scalar_expr!(ArrayReverse, array_reverse, array);
+        test_scalar_expr!(ArraySum, array_sum, array);
         test_scalar_expr!(ArrayToString, array_to_string, array, delimiter);
         test_unary_scalar_expr!(Cardinality, cardinality);
         test_nary_scalar_expr!(MakeArray, array, input);
@@ -590,6 +590,23 @@ fn coerce_arguments_for_fun(
             .collect::<Result<Vec<_>>>()?;
     }
 
+    // Cast the default value of array_element to the element type
+    if *fun == BuiltinScalarFunction::ArrayElement && expressions.len() == 3 {
+        if let DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _) = expressions[0].get_type(schema)?
+        {
+            expressions[2] = cast_expr(&expressions[2], field.data_type(), schema)?;
+        }
+    }
+
+    // Cast the index and length of array_sublist to Int64, a null included
+    if *fun == BuiltinScalarFunction::ArraySublist {
+        for expr in expressions.iter_mut().skip(1) {
+            *expr = cast_expr(expr, &DataType::Int64, schema)?;
+        }
+    }
+
     if *fun == BuiltinScalarFunction::MakeArray {
         // Find the final data type for the function arguments
         let current_types = expressions
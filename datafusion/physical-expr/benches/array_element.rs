@@ -0,0 +1,61 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::{ArrayRef, Int64Array, ListArray};
+use arrow::datatypes::Int64Type;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use datafusion_physical_expr::array_expressions::array_element;
+use std::sync::Arc;
+
+fn do_bench(c: &mut Criterion, name: &str, list_array: &ArrayRef, indexes: Int64Array) {
+    let args = [list_array.clone(), Arc::new(indexes) as ArrayRef];
+    c.bench_function(name, |b| {
+        b.iter(|| black_box(array_element(black_box(&args)).unwrap()))
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let row_count = 8192;
+    // Rows of 10 elements, so that every index up to 10 is in bounds
+    let list_array = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(
+        (0..row_count as i64).map(|row| Some((0..10).map(move |i| Some(row + i)))),
+    )) as ArrayRef;
+
+    // A constant positive index takes the `take` path
+    do_bench(
+        c,
+        "array_element constant index",
+        &list_array,
+        Int64Array::from(vec![3; row_count]),
+    );
+    // A negative index takes the general path
+    do_bench(
+        c,
+        "array_element constant negative index",
+        &list_array,
+        Int64Array::from(vec![-8; row_count]),
+    );
+    do_bench(
+        c,
+        "array_element varying index",
+        &list_array,
+        Int64Array::from_iter_values((0..row_count as i64).map(|row| row % 10 + 1)),
+    );
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
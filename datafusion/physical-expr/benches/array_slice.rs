@@ -0,0 +1,46 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::{ArrayRef, Int64Array, ListArray};
+use arrow::buffer::OffsetBuffer;
+use arrow::datatypes::{DataType, Field};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use datafusion_physical_expr::array_expressions::array_slice;
+use std::sync::Arc;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let row_count = 2_000_000;
+    // Rows of 3 elements, each sliced to its last 2 elements
+    let list_array = Arc::new(ListArray::new(
+        Arc::new(Field::new("item", DataType::Int64, true)),
+        OffsetBuffer::from_lengths(vec![3; row_count]),
+        Arc::new(Int64Array::from_iter_values(0..(row_count as i64 * 3))),
+        None,
+    )) as ArrayRef;
+    let args = [
+        list_array,
+        Arc::new(Int64Array::from_value(2, row_count)) as ArrayRef,
+        Arc::new(Int64Array::from_value(3, row_count)) as ArrayRef,
+    ];
+
+    c.bench_function("array_slice large batch", |b| {
+        b.iter(|| black_box(array_slice(black_box(&args)).unwrap()))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
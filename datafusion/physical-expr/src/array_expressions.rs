@@ -21,25 +21,37 @@ use std::any::type_name;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use arrow::array::timezone::Tz;
 use arrow::array::*;
 use arrow::buffer::OffsetBuffer;
 use arrow::compute;
-use arrow::datatypes::{DataType, Field, UInt64Type};
-use arrow::row::{RowConverter, SortField};
+use arrow::datatypes::{
+    DataType, Date32Type, Decimal128Type, Decimal256Type, Field, Float16Type,
+    Float32Type, Float64Type, Int64Type, IntervalMonthDayNanoType, TimeUnit,
+    TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType, UInt64Type,
+};
+use arrow::row::{RowConverter, Rows, SortField};
 use arrow_buffer::NullBuffer;
 
-use arrow_schema::{FieldRef, SortOptions};
+use arrow_schema::{ArrowError, FieldRef, SortOptions};
 use datafusion_common::cast::{
-    as_generic_list_array, as_generic_string_array, as_int64_array, as_large_list_array,
-    as_list_array, as_null_array, as_string_array,
+    as_boolean_array, as_date32_array, as_fixed_size_list_array, as_float64_array,
+    as_generic_list_array, as_generic_string_array, as_int64_array,
+    as_interval_mdn_array, as_large_list_array, as_list_array, as_null_array,
+    as_string_array,
 };
-use datafusion_common::utils::{array_into_list_array, list_ndims};
+use datafusion_common::utils::array_into_list_array;
 use datafusion_common::{
-    exec_err, internal_err, not_impl_err, plan_err, DataFusionError, Result,
+    exec_err, internal_err, not_impl_err, plan_err, DataFusionError, Result, ScalarValue,
 };
+use datafusion_expr::type_coercion::binary::comparison_coercion;
+use datafusion_expr::BuiltinScalarFunction;
 
 use itertools::Itertools;
 
+use crate::aggregate::min_max::{max_batch, min_batch};
+
 macro_rules! downcast_arg {
     ($ARG:expr, $ARRAY_TYPE:ident) => {{
         $ARG.as_any().downcast_ref::<$ARRAY_TYPE>().ok_or_else(|| {
@@ -51,22 +63,6 @@ macro_rules! downcast_arg {
     }};
 }
 
-/// Downcasts multiple arguments into a single concrete type
-/// $ARGS:  &[ArrayRef]
-/// $ARRAY_TYPE: type to downcast to
-///
-/// $returns a Vec<$ARRAY_TYPE>
-macro_rules! downcast_vec {
-    ($ARGS:expr, $ARRAY_TYPE:ident) => {{
-        $ARGS
-            .iter()
-            .map(|e| match e.as_any().downcast_ref::<$ARRAY_TYPE>() {
-                Some(array) => Ok(array),
-                _ => internal_err!("failed to downcast"),
-            })
-    }};
-}
-
 /// Computes a BooleanArray indicating equality or inequality between elements in a list array and a specified element array.
 ///
 /// # Arguments
@@ -127,6 +123,13 @@ fn compare_element_to_list(
                 .collect::<BooleanArray>()
         }
         _ => {
+            // NaN payloads may differ, all NaNs are treated as equal
+            if let Some(res) =
+                compare_nan_element_to_list(list_array_row, &element_array_row, eq)
+            {
+                return Ok(res);
+            }
+
             let element_arr = Scalar::new(element_array_row);
             // use not_distinct so we can compare NULL
             if eq {
@@ -140,6 +143,41 @@ fn compare_element_to_list(
     Ok(res)
 }
 
+/// Compares a NaN `element_array_row` to each element of `list_array_row`, matching
+/// any NaN regardless of its sign or payload.
+///
+/// Returns `None` if the element is not a floating point NaN.
+fn compare_nan_element_to_list(
+    list_array_row: &dyn Array,
+    element_array_row: &dyn Array,
+    eq: bool,
+) -> Option<BooleanArray> {
+    if list_array_row.data_type() != element_array_row.data_type() {
+        return None;
+    }
+
+    macro_rules! compare_nan {
+        ($ARROW_TYPE:ty) => {{
+            let element = element_array_row.as_primitive::<$ARROW_TYPE>();
+            if element.is_null(0) || !element.value(0).is_nan() {
+                return None;
+            }
+            list_array_row
+                .as_primitive::<$ARROW_TYPE>()
+                .iter()
+                .map(|value| Some(value.is_some_and(|value| value.is_nan()) == eq))
+                .collect()
+        }};
+    }
+
+    match element_array_row.data_type() {
+        DataType::Float16 => Some(compare_nan!(Float16Type)),
+        DataType::Float32 => Some(compare_nan!(Float32Type)),
+        DataType::Float64 => Some(compare_nan!(Float64Type)),
+        _ => None,
+    }
+}
+
 /// Returns the length of a concrete array dimension
 fn compute_array_length(
     arr: Option<ArrayRef>,
@@ -197,6 +235,10 @@ fn compute_array_dims(arr: Option<ArrayRef>) -> Result<Option<Vec<Option<u64>>>>
                 value = downcast_arg!(value, ListArray).value(0);
                 res.push(Some(value.len() as u64));
             }
+            DataType::LargeList(..) => {
+                value = downcast_arg!(value, LargeListArray).value(0);
+                res.push(Some(value.len() as u64));
+            }
             _ => return Ok(Some(res)),
         }
     }
@@ -304,6 +346,31 @@ fn array_array<O: OffsetSizeTrait>(
         return plan_err!("Array requires at least one argument");
     }
 
+    // `MutableArrayData` can not merge dictionaries, so the elements are built
+    // from the decoded values and encoded again
+    if let DataType::Dictionary(_, value_type) = &data_type {
+        let decoded = args
+            .iter()
+            .map(|arg| match arg.data_type() {
+                DataType::Null => Ok(arg.clone()),
+                _ => Ok(compute::cast(arg, value_type)?),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let list_array = array_array::<O>(&decoded, value_type.as_ref().clone())?;
+        let list = as_generic_list_array::<O>(&list_array)?;
+        return match compute::cast(list.values(), &data_type) {
+            Ok(values) => Ok(Arc::new(GenericListArray::<O>::try_new(
+                Arc::new(Field::new("item", data_type, true)),
+                list.offsets().clone(),
+                values,
+                list.nulls().cloned(),
+            )?)),
+            // The merged dictionary has more values than the keys can index
+            Err(ArrowError::DictionaryKeyOverflowError) => Ok(list_array),
+            Err(e) => Err(e.into()),
+        };
+    }
+
     let mut data = vec![];
     let mut total_len = 0;
     for arg in args {
@@ -348,6 +415,11 @@ fn array_array<O: OffsetSizeTrait>(
 }
 
 /// `make_array` SQL function
+///
+/// The element type is the type of the first non-null argument. Dictionary encoded
+/// elements keep their encoding, with the dictionaries of all arguments merged.
+/// When the merged dictionary does not fit the key type, the elements are
+/// decoded to the value type instead.
 pub fn make_array(arrays: &[ArrayRef]) -> Result<ArrayRef> {
     let mut data_type = DataType::Null;
     for arg in arrays {
@@ -374,59 +446,295 @@ pub fn make_array(arrays: &[ArrayRef]) -> Result<ArrayRef> {
 /// There are two arguments for array_element, the first one is the array, the second one is the 1-indexed index.
 /// `array_element(array, index)`
 ///
+/// An optional third argument is returned instead of null when the index is out of bounds
+/// or the array is null.
+/// `array_element(array, index, default)`
+///
+/// When the index is a list, a list of the elements at each of the indexes is returned.
+/// `array_element(array, [index, ...])`
+///
 /// For example:
 /// > array_element(\[1, 2, 3], 2) -> 2
+/// > array_element(\[1, 2, 3], 4, 0) -> 0
+/// > array_element(\[1, 2, 3], \[1, 3]) -> \[1, 3]
 pub fn array_element(args: &[ArrayRef]) -> Result<ArrayRef> {
-    let list_array = as_list_array(&args[0])?;
-    let indexes = as_int64_array(&args[1])?;
+    let default = args.get(2);
+    match (&args[0].data_type(), &args[1].data_type()) {
+        (DataType::List(_), DataType::List(_)) => {
+            let array = as_list_array(&args[0])?;
+            let indexes = as_list_array(&args[1])?;
+            general_array_element_list::<i32>(array, indexes, default)
+        }
+        (DataType::LargeList(_), DataType::List(_)) => {
+            let array = as_large_list_array(&args[0])?;
+            let indexes = as_list_array(&args[1])?;
+            general_array_element_list::<i64>(array, indexes, default)
+        }
+        (DataType::List(_), _) => {
+            let array = as_list_array(&args[0])?;
+            let indexes = as_int64_array(&args[1])?;
+            array_element_by_index::<i32>(array, indexes, default)
+        }
+        (DataType::LargeList(_), _) => {
+            let array = as_large_list_array(&args[0])?;
+            let indexes = as_int64_array(&args[1])?;
+            array_element_by_index::<i64>(array, indexes, default)
+        }
+        (DataType::FixedSizeList(field, _), DataType::List(_)) => {
+            let array = compute::cast(&args[0], &DataType::List(field.clone()))?;
+            let indexes = as_list_array(&args[1])?;
+            general_array_element_list::<i32>(as_list_array(&array)?, indexes, default)
+        }
+        (DataType::FixedSizeList(_, _), _) => {
+            let array = as_fixed_size_list_array(&args[0])?;
+            let indexes = as_int64_array(&args[1])?;
+            general_fixed_size_list_element(array, indexes, default)
+        }
+        _ => not_impl_err!(
+            "array_element does not support type: {:?}",
+            args[0].data_type()
+        ),
+    }
+}
+
+/// Picks the element at `indexes[i]` of each row, with a single `take` when the
+/// index is the same in bounds position for every row.
+fn array_element_by_index<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
+    indexes: &Int64Array,
+    default: Option<&ArrayRef>,
+) -> Result<ArrayRef> {
+    if default.is_none() {
+        if let Some(array) = array_element_constant_index(list_array, indexes)? {
+            return Ok(array);
+        }
+    }
+    general_array_element(list_array, indexes, default)
+}
+
+/// Gathers the element at a constant positive index of every row with one `take`
+/// over the absolute positions `start + index`, null rows give null.
+///
+/// Returns `None` when the index is not constant, not positive or out of bounds
+/// for a row, those are left to `general_array_element`.
+fn array_element_constant_index<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
+    indexes: &Int64Array,
+) -> Result<Option<ArrayRef>> {
+    if indexes.is_empty() || indexes.null_count() > 0 {
+        return Ok(None);
+    }
+    let index = indexes.value(0);
+    if index <= 0 || indexes.values().iter().any(|&other| other != index) {
+        return Ok(None);
+    }
+
+    let zero_index = (index - 1) as usize;
+    let mut take_indices = Vec::with_capacity(list_array.len());
+    for (row_index, offset_window) in list_array.offsets().windows(2).enumerate() {
+        if list_array.is_null(row_index) {
+            take_indices.push(None);
+            continue;
+        }
+        let start = offset_window[0].as_usize();
+        let end = offset_window[1].as_usize();
+        if zero_index >= end - start {
+            return Ok(None);
+        }
+        take_indices.push(Some((start + zero_index) as u64));
+    }
+
+    let take_indices = UInt64Array::from(take_indices);
+    Ok(Some(compute::take(
+        list_array.values(),
+        &take_indices,
+        None,
+    )?))
+}
 
+fn general_array_element<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
+    indexes: &Int64Array,
+    default: Option<&ArrayRef>,
+) -> Result<ArrayRef> {
     let values = list_array.values();
+    // A null typed default is the same as no default
+    let default = default.filter(|default| default.data_type() != &DataType::Null);
+    if let Some(default) = default {
+        check_datatypes("array_element", &[values, default])?;
+    }
+
     let original_data = values.to_data();
+    let default_data = default.map(|default| default.to_data());
     let capacity = Capacities::Array(original_data.len());
 
+    let mut arrays = vec![&original_data];
+    arrays.extend(default_data.as_ref());
     // use_nulls: true, we don't construct List for array_element, so we need explicit nulls.
-    let mut mutable =
-        MutableArrayData::with_capacities(vec![&original_data], true, capacity);
+    let mut mutable = MutableArrayData::with_capacities(arrays, true, capacity);
 
-    fn adjusted_array_index(index: i64, len: usize) -> Option<i64> {
-        // 0 ~ len - 1
-        let adjusted_zero_index = if index < 0 {
-            index + len as i64
+    for (row_index, offset_window) in list_array.offsets().windows(2).enumerate() {
+        let start = offset_window[0].as_usize();
+        let end = offset_window[1].as_usize();
+        let len = end - start;
+
+        // array or index is null
+        let index =
+            if len == 0 || list_array.is_null(row_index) || indexes.is_null(row_index) {
+                None
+            } else {
+                adjusted_array_index(indexes.value(row_index), len)
+            };
+
+        match index {
+            Some(index) => {
+                mutable.extend(0, start + index as usize, start + index as usize + 1)
+            }
+            // Index out of bounds, use the default value if provided
+            None if default_data.is_some() => mutable.extend(1, row_index, row_index + 1),
+            None => mutable.extend_nulls(1),
+        }
+    }
+
+    let data = mutable.freeze();
+    Ok(arrow_array::make_array(data))
+}
+
+/// Handle FixedSizeList version of `general_array_element`
+///
+/// Every row has `list_size` elements, so an index `i` in `-list_size..=list_size`
+/// (except 0) picks an element, with negative indexes counting from the end.
+fn general_fixed_size_list_element(
+    list_array: &FixedSizeListArray,
+    indexes: &Int64Array,
+    default: Option<&ArrayRef>,
+) -> Result<ArrayRef> {
+    let values = list_array.values();
+    // A null typed default is the same as no default
+    let default = default.filter(|default| default.data_type() != &DataType::Null);
+    if let Some(default) = default {
+        check_datatypes("array_element", &[values, default])?;
+    }
+
+    let original_data = values.to_data();
+    let default_data = default.map(|default| default.to_data());
+    let capacity = Capacities::Array(list_array.len());
+
+    let mut arrays = vec![&original_data];
+    arrays.extend(default_data.as_ref());
+    let mut mutable = MutableArrayData::with_capacities(arrays, true, capacity);
+
+    let list_size = list_array.value_length() as usize;
+    for row_index in 0..list_array.len() {
+        let index = if list_array.is_null(row_index) || indexes.is_null(row_index) {
+            None
         } else {
-            index - 1
+            adjusted_array_index(indexes.value(row_index), list_size)
         };
 
-        if 0 <= adjusted_zero_index && adjusted_zero_index < len as i64 {
-            Some(adjusted_zero_index)
-        } else {
-            // Out of bounds
-            None
+        match index {
+            Some(index) => {
+                let start = list_array.value_offset(row_index) as usize + index as usize;
+                mutable.extend(0, start, start + 1)
+            }
+            // Index out of bounds, use the default value if provided
+            None if default_data.is_some() => mutable.extend(1, row_index, row_index + 1),
+            None => mutable.extend_nulls(1),
         }
     }
 
-    for (row_index, offset_window) in list_array.offsets().windows(2).enumerate() {
-        let start = offset_window[0] as usize;
-        let end = offset_window[1] as usize;
-        let len = end - start;
+    let data = mutable.freeze();
+    Ok(arrow_array::make_array(data))
+}
 
-        // array is null
-        if len == 0 {
-            mutable.extend_nulls(1);
+/// Handle list indexes version of `general_array_element`
+///
+/// For each index of `indexes[i]` pick the element of `list_array[i]`. An index out of
+/// bounds picks the default value if provided, otherwise null. The row is null if either
+/// the array or the indexes are null.
+fn general_array_element_list<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
+    indexes: &ListArray,
+    default: Option<&ArrayRef>,
+) -> Result<ArrayRef> {
+    let values = list_array.values();
+    let index_values = as_int64_array(indexes.values())?;
+    // A null typed default is the same as no default
+    let default = default.filter(|default| default.data_type() != &DataType::Null);
+    if let Some(default) = default {
+        check_datatypes("array_element", &[values, default])?;
+    }
+
+    let original_data = values.to_data();
+    let default_data = default.map(|default| default.to_data());
+    let capacity = Capacities::Array(index_values.len());
+
+    let mut arrays = vec![&original_data];
+    arrays.extend(default_data.as_ref());
+    let mut mutable = MutableArrayData::with_capacities(arrays, true, capacity);
+
+    let mut offsets = Vec::with_capacity(list_array.len() + 1);
+    offsets.push(O::usize_as(0));
+    let mut valid = BooleanBufferBuilder::new(list_array.len());
+
+    for (row_index, offset_window) in list_array.offsets().windows(2).enumerate() {
+        if list_array.is_null(row_index) || indexes.is_null(row_index) {
+            offsets.push(O::usize_as(mutable.len()));
+            valid.append(false);
             continue;
         }
 
-        let index = adjusted_array_index(indexes.value(row_index), len);
+        let start = offset_window[0].as_usize();
+        let len = offset_window[1].as_usize() - start;
+        let index_start = indexes.value_offsets()[row_index] as usize;
+        let index_end = indexes.value_offsets()[row_index + 1] as usize;
 
-        if let Some(index) = index {
-            mutable.extend(0, start + index as usize, start + index as usize + 1);
-        } else {
-            // Index out of bounds
-            mutable.extend_nulls(1);
+        for i in index_start..index_end {
+            let index = if index_values.is_null(i) {
+                None
+            } else {
+                adjusted_array_index(index_values.value(i), len)
+            };
+
+            match index {
+                Some(index) => {
+                    mutable.extend(0, start + index as usize, start + index as usize + 1)
+                }
+                None if default_data.is_some() => {
+                    mutable.extend(1, row_index, row_index + 1)
+                }
+                None => mutable.extend_nulls(1),
+            }
         }
+        offsets.push(O::usize_as(mutable.len()));
+        valid.append(true);
     }
 
     let data = mutable.freeze();
-    Ok(arrow_array::make_array(data))
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        Arc::new(Field::new("item", list_array.value_type(), true)),
+        OffsetBuffer::new(offsets.into()),
+        arrow_array::make_array(data),
+        Some(NullBuffer::new(valid.finish())),
+    )?))
+}
+
+/// Converts a 1-indexed `index` into a 0-indexed one within an array of `len`.
+/// Negative indexes count from the end, out of bounds indexes return `None`.
+fn adjusted_array_index(index: i64, len: usize) -> Option<i64> {
+    // 0 ~ len - 1
+    let adjusted_zero_index = if index < 0 {
+        index + len as i64
+    } else {
+        index - 1
+    };
+
+    if 0 <= adjusted_zero_index && adjusted_zero_index < len as i64 {
+        Some(adjusted_zero_index)
+    } else {
+        // Out of bounds
+        None
+    }
 }
 
 fn general_except<OffsetSize: OffsetSizeTrait>(
@@ -447,12 +755,26 @@ fn general_except<OffsetSize: OffsetSizeTrait>(
     let mut rows = Vec::with_capacity(l_values.num_rows());
     let mut dedup = HashSet::new();
 
-    for (l_w, r_w) in l.offsets().windows(2).zip(r.offsets().windows(2)) {
+    for (row_index, (l_w, r_w)) in l
+        .offsets()
+        .windows(2)
+        .zip(r.offsets().windows(2))
+        .enumerate()
+    {
+        // A null row may still be backed by values, which must be ignored:
+        // a null left row stays null (and empty), a null right row removes nothing.
+        if l.is_null(row_index) {
+            offsets.push(OffsetSize::usize_as(rows.len()));
+            continue;
+        }
+
         let l_slice = l_w[0].as_usize()..l_w[1].as_usize();
-        let r_slice = r_w[0].as_usize()..r_w[1].as_usize();
-        for i in r_slice {
-            let right_row = r_values.row(i);
-            dedup.insert(right_row);
+        if r.is_valid(row_index) {
+            let r_slice = r_w[0].as_usize()..r_w[1].as_usize();
+            for i in r_slice {
+                let right_row = r_values.row(i);
+                dedup.insert(right_row);
+            }
         }
         for i in l_slice {
             let left_row = l_values.row(i);
@@ -477,16 +799,29 @@ fn general_except<OffsetSize: OffsetSizeTrait>(
     }
 }
 
+/// array_except SQL function
+///
+/// With more than two arrays, the difference is folded from left to right:
+/// `array_except(a, b, c)` is `array_except(array_except(a, b), c)`.
+/// A null subtrahend leaves the array unchanged.
 pub fn array_except(args: &[ArrayRef]) -> Result<ArrayRef> {
-    if args.len() != 2 {
-        return internal_err!("array_except needs two arguments");
+    if args.len() < 2 {
+        return internal_err!("array_except needs at least two arguments");
     }
 
-    let array1 = &args[0];
-    let array2 = &args[1];
+    args[1..]
+        .iter()
+        .try_fold(args[0].clone(), |array, subtrahend| {
+            array_except_pair(&array, subtrahend)
+        })
+}
 
+fn array_except_pair(array1: &ArrayRef, array2: &ArrayRef) -> Result<ArrayRef> {
     match (array1.data_type(), array2.data_type()) {
         (DataType::Null, _) | (_, DataType::Null) => Ok(array1.to_owned()),
+        (DataType::FixedSizeList(_, _), _) | (_, DataType::FixedSizeList(_, _)) => {
+            not_impl_err!("array_except does not support FixedSizeList arguments")
+        }
         (DataType::List(field), DataType::List(_)) => {
             check_datatypes("array_except", &[array1, array2])?;
             let list1 = array1.as_list::<i32>();
@@ -514,6 +849,8 @@ pub fn array_except(args: &[ArrayRef]) -> Result<ArrayRef> {
 ///
 /// > array_slice(array, from, to)
 ///
+/// The `to` argument is optional, when it is omitted the slice extends to the end of the array.
+///
 /// Positive index is treated as the index from the start of the array. If the
 /// `from` index is smaller than 1, it is treated as 1. If the `to` index is larger than the
 /// length of the array, it is treated as the length of the array.
@@ -522,11 +859,66 @@ pub fn array_except(args: &[ArrayRef]) -> Result<ArrayRef> {
 /// is larger than the length of the array, it is NOT VALID, either in `from` or `to`.
 /// The `to` index is exclusive like python slice syntax.
 ///
+/// array_slice never reverses the elements: if the resolved `from` index is after the
+/// resolved `to` index, the result is an empty array.
+///
+/// The optional fourth argument `null_on_invalid` returns null instead of an empty
+/// array for such an invalid range, e.g. array_slice(\[1, 2, 3], 3, 1, true) is null.
+///
+/// The arguments are positional, so the supported forms are
+/// > array_slice(array, from)
+/// > array_slice(array, from, to)
+/// > array_slice(array, from, to, null_on_invalid)
+///
 /// See test cases in `array.slt` for more details.
 pub fn array_slice(args: &[ArrayRef]) -> Result<ArrayRef> {
-    let list_array = as_list_array(&args[0])?;
+    if args.len() < 2 || args.len() > 4 {
+        return exec_err!("array_slice needs two to four arguments");
+    }
     let from_array = as_int64_array(&args[1])?;
-    let to_array = as_int64_array(&args[2])?;
+    // A missing `to` slices to the end of each array, the same as a null index
+    let default_to_array;
+    let to_array = match args.get(2) {
+        Some(to_array) => as_int64_array(to_array)?,
+        None => {
+            default_to_array = Int64Array::new_null(args[0].len());
+            &default_to_array
+        }
+    };
+
+    let null_on_invalid = args.get(3).map(|arg| as_boolean_array(arg)).transpose()?;
+
+    match args[0].data_type() {
+        DataType::List(_) => {
+            let list_array = as_list_array(&args[0])?;
+            general_array_slice::<i32>(list_array, from_array, to_array, null_on_invalid)
+        }
+        DataType::LargeList(_) => {
+            let list_array = as_large_list_array(&args[0])?;
+            general_array_slice::<i64>(list_array, from_array, to_array, null_on_invalid)
+        }
+        data_type => plan_err!(
+            "array_slice can only accept list as the first argument, got {data_type}"
+        ),
+    }
+}
+
+/// Slices each row of `list_array`, see [`array_slice`] for the index rules.
+fn general_array_slice<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
+    from_array: &Int64Array,
+    to_array: &Int64Array,
+    null_on_invalid: Option<&BooleanArray>,
+) -> Result<ArrayRef> {
+    // Scalar indexes are already expanded to the number of rows by the caller
+    if from_array.len() != list_array.len() || to_array.len() != list_array.len() {
+        return exec_err!(
+            "array_slice expects from and to indexes with the same number of rows as the array ({}), got {} and {}",
+            list_array.len(),
+            from_array.len(),
+            to_array.len()
+        );
+    }
 
     let values = list_array.values();
     let original_data = values.to_data();
@@ -574,16 +966,19 @@ pub fn array_slice(args: &[ArrayRef]) -> Result<ArrayRef> {
         }
     }
 
-    let mut offsets = vec![0];
+    let mut offsets = Vec::with_capacity(list_array.len() + 1);
+    offsets.push(O::usize_as(0));
+    let mut valid = BooleanBufferBuilder::new(list_array.len());
 
     for (row_index, offset_window) in list_array.offsets().windows(2).enumerate() {
-        let start = offset_window[0] as usize;
-        let end = offset_window[1] as usize;
+        let start = offset_window[0].as_usize();
+        let end = offset_window[1].as_usize();
         let len = end - start;
 
         // len 0 indicate array is null, return empty array in this row.
         if len == 0 {
-            offsets.push(offsets[row_index]);
+            offsets.push(O::usize_as(mutable.len()));
+            valid.append(true);
             continue;
         }
 
@@ -600,103 +995,261 @@ pub fn array_slice(args: &[ArrayRef]) -> Result<ArrayRef> {
             adjusted_to_index(to_array.value(row_index), len)
         };
 
+        // invalid range leaves the offset unchanged, return empty array
+        let mut is_valid_range = false;
         if let (Some(from), Some(to)) = (from_index, to_index) {
             if from <= to {
-                assert!(start + to as usize <= end);
-                mutable.extend(0, start + from as usize, start + to as usize + 1);
-                offsets.push(offsets[row_index] + (to - from + 1) as i32);
-            } else {
-                // invalid range, return empty array
-                offsets.push(offsets[row_index]);
+                let (from, to) = (slice_index_to_usize(from)?, slice_index_to_usize(to)?);
+                assert!(start + to <= end);
+                mutable.extend(0, start + from, start + to + 1);
+                is_valid_range = true;
             }
-        } else {
-            // invalid range, return empty array
-            offsets.push(offsets[row_index]);
         }
+        // The offset follows what was actually copied, so it can't drift from the values
+        offsets.push(O::usize_as(mutable.len()));
+        // a null flag falls back to the default of returning an empty array
+        let null_on_invalid = null_on_invalid.is_some_and(|null_on_invalid| {
+            null_on_invalid.is_valid(row_index) && null_on_invalid.value(row_index)
+        });
+        valid.append(is_valid_range || !null_on_invalid);
     }
 
-    let data = mutable.freeze();
+    // Nothing was selected in any row, so skip freezing the preallocated values
+    let values = if mutable.is_empty() {
+        new_empty_array(&list_array.value_type())
+    } else {
+        arrow_array::make_array(mutable.freeze())
+    };
+    let nulls = null_on_invalid.map(|_| NullBuffer::new(valid.finish()));
 
-    Ok(Arc::new(ListArray::try_new(
+    Ok(Arc::new(GenericListArray::<O>::try_new(
         Arc::new(Field::new("item", list_array.value_type(), true)),
         OffsetBuffer::new(offsets.into()),
-        arrow_array::make_array(data),
-        None,
+        values,
+        nulls,
     )?))
 }
 
-/// array_pop_back SQL function
-pub fn array_pop_back(args: &[ArrayRef]) -> Result<ArrayRef> {
-    let list_array = as_list_array(&args[0])?;
-    let from_array = Int64Array::from(vec![1; list_array.len()]);
-    let to_array = Int64Array::from(
-        list_array
-            .iter()
-            .map(|arr| arr.map_or(0, |arr| arr.len() as i64 - 1))
-            .collect::<Vec<i64>>(),
-    );
-    let args = vec![args[0].clone(), Arc::new(from_array), Arc::new(to_array)];
-    array_slice(args.as_slice())
+/// Converts an adjusted `array_slice` index to `usize`, failing instead of wrapping
+/// around on targets where `usize` is narrower than `i64`.
+fn slice_index_to_usize(index: i64) -> Result<usize> {
+    match usize::try_from(index) {
+        Ok(index) => Ok(index),
+        Err(_) => exec_err!("array_slice index {index} is out of range for usize"),
+    }
 }
 
-/// Appends or prepends elements to a ListArray.
-///
-/// This function takes a ListArray, an ArrayRef, a FieldRef, and a boolean flag
-/// indicating whether to append or prepend the elements. It returns a `Result<ArrayRef>`
-/// representing the resulting ListArray after the operation.
+/// array_sublist SQL function
 ///
-/// # Arguments
+/// Returns up to `length` elements of each array, starting at the 1-indexed `index`.
+/// Unlike [`array_slice`], which takes a `from` and `to` index, the sublist is given
+/// by a position and a count.
 ///
-/// * `list_array` - A reference to the ListArray to which elements will be appended/prepended.
-/// * `element_array` - A reference to the Array containing elements to be appended/prepended.
-/// * `field` - A reference to the Field describing the data type of the arrays.
-/// * `is_append` - A boolean flag indicating whether to append (`true`) or prepend (`false`) elements.
+/// > array_sublist(array, index, length)
 ///
-/// # Examples
+/// Negative index is treated as the index from the end of the array, like in
+/// `array_element`. If fewer than `length` elements remain after `index`, the sublist
+/// is truncated to the end of the array. An out of bounds `index` or a `length`
+/// smaller than 1 returns an empty array, and a null array, `index` or `length`
+/// returns null.
 ///
-/// general_append_and_prepend(
-///     [1, 2, 3], 4, append => [1, 2, 3, 4]
-///     5, [6, 7, 8], prepend => [5, 6, 7, 8]
-/// )
-fn general_append_and_prepend(
-    list_array: &ListArray,
-    element_array: &ArrayRef,
-    data_type: &DataType,
-    is_append: bool,
-) -> Result<ArrayRef> {
-    let mut offsets = vec![0];
-    let values = list_array.values();
-    let original_data = values.to_data();
-    let element_data = element_array.to_data();
-    let capacity = Capacities::Array(original_data.len() + element_data.len());
-
-    let mut mutable = MutableArrayData::with_capacities(
-        vec![&original_data, &element_data],
-        false,
-        capacity,
-    );
-
-    let values_index = 0;
-    let element_index = 1;
+/// For example,
+/// > array_sublist(\[1, 2, 3, 4], 2, 2) => \[2, 3]
+/// > array_sublist(\[1, 2, 3, 4], 3, 5) => \[3, 4]
+/// > array_sublist(\[1, 2, 3, 4], -2, 1) => \[3]
+pub fn array_sublist(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if args.len() != 3 {
+        return exec_err!("array_sublist needs three arguments");
+    }
+    let index_array = as_int64_array(&args[1])?;
+    let length_array = as_int64_array(&args[2])?;
+
+    match args[0].data_type() {
+        DataType::List(_) => {
+            let list_array = as_list_array(&args[0])?;
+            general_array_sublist::<i32>(list_array, index_array, length_array)
+        }
+        DataType::LargeList(_) => {
+            let list_array = as_large_list_array(&args[0])?;
+            general_array_sublist::<i64>(list_array, index_array, length_array)
+        }
+        data_type => plan_err!(
+            "array_sublist can only accept list as the first argument, got {data_type}"
+        ),
+    }
+}
+
+/// Takes the sublist of each row of `list_array`, see [`array_sublist`] for the rules.
+fn general_array_sublist<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
+    index_array: &Int64Array,
+    length_array: &Int64Array,
+) -> Result<ArrayRef> {
+    // Scalar arguments are already expanded to the number of rows by the caller
+    if index_array.len() != list_array.len() || length_array.len() != list_array.len() {
+        return exec_err!(
+            "array_sublist expects index and length with the same number of rows as the array ({}), got {} and {}",
+            list_array.len(),
+            index_array.len(),
+            length_array.len()
+        );
+    }
+
+    let original_data = list_array.values().to_data();
+    let capacity = Capacities::Array(original_data.len());
+    let mut mutable =
+        MutableArrayData::with_capacities(vec![&original_data], false, capacity);
+
+    let mut offsets = Vec::with_capacity(list_array.len() + 1);
+    offsets.push(O::usize_as(0));
+    let mut valid = BooleanBufferBuilder::new(list_array.len());
+
+    for (row_index, offset_window) in list_array.offsets().windows(2).enumerate() {
+        if list_array.is_null(row_index)
+            || index_array.is_null(row_index)
+            || length_array.is_null(row_index)
+        {
+            offsets.push(O::usize_as(mutable.len()));
+            valid.append(false);
+            continue;
+        }
+
+        let start = offset_window[0].as_usize();
+        let len = offset_window[1].as_usize() - start;
+        let length = length_array.value(row_index);
+
+        // An out of bounds index or a non-positive length leaves the row empty
+        if let Some(from) = adjusted_array_index(index_array.value(row_index), len) {
+            if length > 0 {
+                // `from` is within `0..len`, so it fits in a usize
+                let from = from as usize;
+                let remaining = len - from;
+                // A length past the end of the array is truncated to what remains
+                let count = usize::try_from(length)
+                    .map_or(remaining, |length| length.min(remaining));
+                mutable.extend(0, start + from, start + from + count);
+            }
+        }
+        offsets.push(O::usize_as(mutable.len()));
+        valid.append(true);
+    }
+
+    let values = if mutable.is_empty() {
+        new_empty_array(&list_array.value_type())
+    } else {
+        arrow_array::make_array(mutable.freeze())
+    };
+
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        Arc::new(Field::new("item", list_array.value_type(), true)),
+        OffsetBuffer::new(offsets.into()),
+        values,
+        Some(NullBuffer::new(valid.finish())),
+    )?))
+}
+
+/// Returns the length of each row of a `List` or `LargeList` array, `None` for null rows.
+fn list_row_lengths(name: &str, array: &ArrayRef) -> Result<Vec<Option<i64>>> {
+    fn row_lengths<O: OffsetSizeTrait>(
+        list_array: &GenericListArray<O>,
+    ) -> Vec<Option<i64>> {
+        list_array
+            .iter()
+            .map(|arr| arr.map(|arr| arr.len() as i64))
+            .collect()
+    }
+
+    match array.data_type() {
+        DataType::List(_) => Ok(row_lengths(as_list_array(array)?)),
+        DataType::LargeList(_) => Ok(row_lengths(as_large_list_array(array)?)),
+        data_type => {
+            plan_err!("{name} can only accept list as the argument, got {data_type}")
+        }
+    }
+}
+
+/// array_pop_back SQL function
+pub fn array_pop_back(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let lengths = list_row_lengths("array_pop_back", &args[0])?;
+    let from_array = Int64Array::from(vec![1; lengths.len()]);
+    let to_array = Int64Array::from(
+        lengths
+            .iter()
+            .map(|len| len.map_or(0, |len| len - 1))
+            .collect::<Vec<i64>>(),
+    );
+    let args = vec![args[0].clone(), Arc::new(from_array), Arc::new(to_array)];
+    array_slice(args.as_slice())
+}
+
+/// Appends or prepends elements to a ListArray.
+///
+/// This function takes a ListArray, an ArrayRef, a FieldRef, and a boolean flag
+/// indicating whether to append or prepend the elements. It returns a `Result<ArrayRef>`
+/// representing the resulting ListArray after the operation.
+///
+/// # Arguments
+///
+/// * `list_array` - A reference to the ListArray to which elements will be appended/prepended.
+/// * `element_array` - A reference to the Array containing elements to be appended/prepended.
+/// * `field` - A reference to the Field describing the data type of the arrays.
+/// * `is_append` - A boolean flag indicating whether to append (`true`) or prepend (`false`) elements.
+///
+/// # Examples
+///
+/// general_append_and_prepend(
+///     [1, 2, 3], 4, append => [1, 2, 3, 4]
+///     5, [6, 7, 8], prepend => [5, 6, 7, 8]
+/// )
+fn general_append_and_prepend(
+    list_array: &ListArray,
+    element_array: &ArrayRef,
+    data_type: &DataType,
+    is_append: bool,
+) -> Result<ArrayRef> {
+    let mut lengths = Vec::with_capacity(list_array.len());
+    let values = list_array.values();
+    let original_data = values.to_data();
+    let element_data = element_array.to_data();
+    let capacity = Capacities::Array(original_data.len() + element_data.len());
+
+    let mut mutable = MutableArrayData::with_capacities(
+        vec![&original_data, &element_data],
+        false,
+        capacity,
+    );
+
+    let values_index = 0;
+    let element_index = 1;
+
+    // A single element is broadcast to every row
+    let element_len = element_array.len();
+    if element_len != 1 && element_len != list_array.len() {
+        return exec_err!(
+            "Expected an element for each of the {} rows or a single element, got {element_len}",
+            list_array.len()
+        );
+    }
 
     for (row_index, offset_window) in list_array.offsets().windows(2).enumerate() {
         let start = offset_window[0] as usize;
         let end = offset_window[1] as usize;
+        let element_row = if element_len == 1 { 0 } else { row_index };
         if is_append {
             mutable.extend(values_index, start, end);
-            mutable.extend(element_index, row_index, row_index + 1);
+            mutable.extend(element_index, element_row, element_row + 1);
         } else {
-            mutable.extend(element_index, row_index, row_index + 1);
+            mutable.extend(element_index, element_row, element_row + 1);
             mutable.extend(values_index, start, end);
         }
-        offsets.push(offsets[row_index] + (end - start + 1) as i32);
+        lengths.push(end - start + 1);
     }
 
     let data = mutable.freeze();
 
     Ok(Arc::new(ListArray::try_new(
         Arc::new(Field::new("item", data_type.to_owned(), true)),
-        OffsetBuffer::new(offsets.into()),
+        OffsetBuffer::from_lengths(lengths),
         arrow_array::make_array(data),
         None,
     )?))
@@ -716,7 +1269,22 @@ fn general_append_and_prepend(
 /// gen_range(3) => [0, 1, 2]
 /// gen_range(1, 4) => [1, 2, 3]
 /// gen_range(1, 7, 2) => [1, 3, 5]
+/// gen_range(0.0, 1.0, 0.25) => [0.0, 0.25, 0.5, 0.75]
+///
+/// Dates and timestamps are stepped by an `IntervalMonthDayNano`, a negative
+/// interval counts down from start to stop.
+///
+/// gen_range(2023-01-01, 2023-01-04, 1 day) => [2023-01-01, 2023-01-02, 2023-01-03]
 pub fn gen_range(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if let [start, _, _] = args {
+        match start.data_type() {
+            DataType::Float64 => return gen_range_float(args),
+            DataType::Date32 => return gen_range_date(args),
+            DataType::Timestamp(_, _) => return gen_range_timestamp(args),
+            _ => {}
+        }
+    }
+
     let (start_array, stop_array, step_array) = match args.len() {
         1 => (None, as_int64_array(&args[0])?, None),
         2 => (
@@ -733,7 +1301,8 @@ pub fn gen_range(args: &[ArrayRef]) -> Result<ArrayRef> {
     };
 
     let mut values = vec![];
-    let mut offsets = vec![0];
+    let mut offsets = Vec::with_capacity(stop_array.len() + 1);
+    offsets.push(0);
     for (idx, stop) in stop_array.iter().enumerate() {
         let stop = stop.unwrap_or(0);
         let start = start_array.as_ref().map(|arr| arr.value(idx)).unwrap_or(0);
@@ -760,14 +1329,192 @@ pub fn gen_range(args: &[ArrayRef]) -> Result<ArrayRef> {
     Ok(arr)
 }
 
+/// Generates a list of `Float64` values from start to stop (exclusive) for each row
+/// by repeatedly adding step, a negative step counts down. A row with a null
+/// argument is null.
+fn gen_range_float(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let start_array = as_float64_array(&args[0])?;
+    let stop_array = as_float64_array(&args[1])?;
+    let step_array = as_float64_array(&args[2])?;
+
+    let mut values = vec![];
+    let mut offsets = Vec::with_capacity(start_array.len() + 1);
+    offsets.push(0);
+    let mut valid = BooleanBufferBuilder::new(start_array.len());
+    for row_index in 0..start_array.len() {
+        if start_array.is_null(row_index)
+            || stop_array.is_null(row_index)
+            || step_array.is_null(row_index)
+        {
+            offsets.push(values.len() as i32);
+            valid.append(false);
+            continue;
+        }
+
+        let start = start_array.value(row_index);
+        let stop = stop_array.value(row_index);
+        let step = step_array.value(row_index);
+        if step == 0.0 {
+            return exec_err!("step can't be 0 for function range(start [, stop, step]");
+        }
+        if !(start.is_finite() && stop.is_finite() && step.is_finite()) {
+            return exec_err!(
+                "start, stop and step of function range(start, stop, step) must be finite, got {start}, {stop} and {step}"
+            );
+        }
+
+        let mut value = start;
+        while (step > 0.0 && value < stop) || (step < 0.0 && value > stop) {
+            values.push(value);
+            let next = value + step;
+            // A step below the precision of value would never reach stop
+            if next == value {
+                return exec_err!(
+                    "step of function range(start, stop, step) does not change the Float64 value"
+                );
+            }
+            value = next;
+        }
+        offsets.push(values.len() as i32);
+        valid.append(true);
+    }
+
+    Ok(Arc::new(ListArray::try_new(
+        Arc::new(Field::new("item", DataType::Float64, true)),
+        OffsetBuffer::new(offsets.into()),
+        Arc::new(Float64Array::from(values)),
+        Some(NullBuffer::new(valid.finish())),
+    )?))
+}
+
+/// Generates a list of `Date32` values from start to stop (exclusive) for each row.
+fn gen_range_date(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let start_array = as_date32_array(&args[0])?;
+    let stop_array = compute::cast(&args[1], &DataType::Date32)?;
+    let step_array = as_interval_mdn_array(&args[2])?;
+    gen_temporal_range(
+        start_array,
+        as_date32_array(&stop_array)?,
+        step_array,
+        |date, step| Some(Date32Type::add_month_day_nano(date, step)),
+    )
+}
+
+/// Generates a list of timestamps from start to stop (exclusive) for each row,
+/// stepping in the timezone of start so that a day is a calendar day.
+fn gen_range_timestamp(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let data_type = args[0].data_type();
+    let DataType::Timestamp(unit, tz) = data_type else {
+        return internal_err!("gen_range_timestamp expects a timestamp, got {data_type}");
+    };
+    let tz: Tz = tz.as_deref().unwrap_or("+00:00").parse()?;
+    let stop_array = compute::cast(&args[1], data_type)?;
+    let step_array = as_interval_mdn_array(&args[2])?;
+
+    match unit {
+        TimeUnit::Second => gen_temporal_range(
+            args[0].as_primitive::<TimestampSecondType>(),
+            stop_array.as_primitive(),
+            step_array,
+            |ts, step| TimestampSecondType::add_month_day_nano(ts, step, tz),
+        ),
+        TimeUnit::Millisecond => gen_temporal_range(
+            args[0].as_primitive::<TimestampMillisecondType>(),
+            stop_array.as_primitive(),
+            step_array,
+            |ts, step| TimestampMillisecondType::add_month_day_nano(ts, step, tz),
+        ),
+        TimeUnit::Microsecond => gen_temporal_range(
+            args[0].as_primitive::<TimestampMicrosecondType>(),
+            stop_array.as_primitive(),
+            step_array,
+            |ts, step| TimestampMicrosecondType::add_month_day_nano(ts, step, tz),
+        ),
+        TimeUnit::Nanosecond => gen_temporal_range(
+            args[0].as_primitive::<TimestampNanosecondType>(),
+            stop_array.as_primitive(),
+            step_array,
+            |ts, step| TimestampNanosecondType::add_month_day_nano(ts, step, tz),
+        ),
+    }
+}
+
+/// Steps from start towards stop (exclusive) with `add_step` for each row, the
+/// direction is the sign of the step. A row with a null argument is null.
+fn gen_temporal_range<T: ArrowPrimitiveType>(
+    start_array: &PrimitiveArray<T>,
+    stop_array: &PrimitiveArray<T>,
+    step_array: &IntervalMonthDayNanoArray,
+    add_step: impl Fn(T::Native, i128) -> Option<T::Native>,
+) -> Result<ArrayRef> {
+    let data_type = start_array.data_type();
+    let mut values = vec![];
+    let mut offsets = Vec::with_capacity(start_array.len() + 1);
+    offsets.push(0);
+    let mut valid = BooleanBufferBuilder::new(start_array.len());
+    for row_index in 0..start_array.len() {
+        if start_array.is_null(row_index)
+            || stop_array.is_null(row_index)
+            || step_array.is_null(row_index)
+        {
+            offsets.push(values.len() as i32);
+            valid.append(false);
+            continue;
+        }
+
+        let start = start_array.value(row_index);
+        let stop = stop_array.value(row_index);
+        let step = step_array.value(row_index);
+        if step == 0 {
+            return exec_err!("step can't be 0 for function range(start [, stop, step]");
+        }
+
+        // A step with parts of both signs could change direction and never reach stop
+        let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(step);
+        let descending = months < 0 || days < 0 || nanos < 0;
+        if descending && (months > 0 || days > 0 || nanos > 0) {
+            return exec_err!(
+                "step of function range(start, stop, step) can't mix positive and negative parts, got {months} months, {days} days and {nanos} nanoseconds"
+            );
+        }
+
+        let mut value = start;
+        // stop is checked first, a step past stop may overflow the type
+        while (descending && value > stop) || (!descending && value < stop) {
+            values.push(value);
+            let Some(next) = add_step(value, step) else {
+                return exec_err!("range of {data_type} values overflowed");
+            };
+            // A step too small for the type, e.g. an hour for a date, never reaches stop
+            if (descending && next >= value) || (!descending && next <= value) {
+                return exec_err!(
+                    "step of function range(start, stop, step) does not change the {data_type} value"
+                );
+            }
+            value = next;
+        }
+        offsets.push(values.len() as i32);
+        valid.append(true);
+    }
+
+    let values =
+        PrimitiveArray::<T>::new(values.into(), None).with_data_type(data_type.clone());
+    Ok(Arc::new(ListArray::try_new(
+        Arc::new(Field::new("item", data_type.clone(), true)),
+        OffsetBuffer::new(offsets.into()),
+        Arc::new(values),
+        Some(NullBuffer::new(valid.finish())),
+    )?))
+}
+
 /// array_pop_front SQL function
 pub fn array_pop_front(args: &[ArrayRef]) -> Result<ArrayRef> {
-    let list_array = as_list_array(&args[0])?;
-    let from_array = Int64Array::from(vec![2; list_array.len()]);
+    let lengths = list_row_lengths("array_pop_front", &args[0])?;
+    let from_array = Int64Array::from(vec![2; lengths.len()]);
     let to_array = Int64Array::from(
-        list_array
+        lengths
             .iter()
-            .map(|arr| arr.map_or(0, |arr| arr.len() as i64))
+            .map(|len| len.unwrap_or(0))
             .collect::<Vec<i64>>(),
     );
     let args = vec![args[0].clone(), Arc::new(from_array), Arc::new(to_array)];
@@ -781,7 +1528,17 @@ pub fn array_append(args: &[ArrayRef]) -> Result<ArrayRef> {
 
     check_datatypes("array_append", &[list_array.values(), element_array])?;
     let res = match list_array.value_type() {
-        DataType::List(_) => concat_internal(args)?,
+        DataType::List(_) => concat_internal::<i32>(args)?,
+        // `concat_internal` only aligns `List` dimensions, a nested `LargeList`
+        // element is appended as a single value instead
+        DataType::LargeList(_) => {
+            return general_append_and_prepend(
+                list_array,
+                element_array,
+                &list_array.value_type(),
+                true,
+            );
+        }
         DataType::Null => return make_array(&[element_array.to_owned()]),
         data_type => {
             return general_append_and_prepend(
@@ -801,11 +1558,20 @@ pub fn array_sort(args: &[ArrayRef]) -> Result<ArrayRef> {
     let sort_option = match args.len() {
         1 => None,
         2 => {
-            let sort = as_string_array(&args[1])?.value(0);
-            Some(SortOptions {
-                descending: order_desc(sort)?,
-                nulls_first: true,
-            })
+            // The second parameter is either the direction or the nulls ordering,
+            // in which case the direction defaults to ASC
+            let modifier = as_string_array(&args[1])?.value(0);
+            if is_nulls_order(modifier) {
+                Some(SortOptions {
+                    descending: false,
+                    nulls_first: order_nulls_first(modifier)?,
+                })
+            } else {
+                Some(SortOptions {
+                    descending: order_desc(modifier)?,
+                    nulls_first: true,
+                })
+            }
         }
         3 => {
             let sort = as_string_array(&args[1])?.value(0);
@@ -818,41 +1584,83 @@ pub fn array_sort(args: &[ArrayRef]) -> Result<ArrayRef> {
         _ => return internal_err!("array_sort expects 1 to 3 arguments"),
     };
 
-    let list_array = as_list_array(&args[0])?;
+    match args[0].data_type() {
+        DataType::List(_) => {
+            general_array_sort::<i32>(as_list_array(&args[0])?, sort_option)
+        }
+        DataType::LargeList(_) => {
+            general_array_sort::<i64>(as_large_list_array(&args[0])?, sort_option)
+        }
+        array_type => exec_err!("array_sort does not support type '{array_type:?}'."),
+    }
+}
+
+/// Sorts each row of `list_array`, null rows stay null.
+fn general_array_sort<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
+    sort_option: Option<SortOptions>,
+) -> Result<ArrayRef> {
     let row_count = list_array.len();
 
+    // `compute::sort` does not support nested types, those are sorted by their row format
+    let converter = match list_array.value_type() {
+        data_type @ (DataType::List(_) | DataType::LargeList(_)) => {
+            Some(RowConverter::new(vec![SortField::new_with_options(
+                data_type,
+                sort_option.unwrap_or_default(),
+            )])?)
+        }
+        _ => None,
+    };
+
     let mut array_lengths = vec![];
     let mut arrays = vec![];
-    let mut valid = BooleanBufferBuilder::new(row_count);
     for i in 0..row_count {
         if list_array.is_null(i) {
             array_lengths.push(0);
-            valid.append(false);
         } else {
             let arr_ref = list_array.value(i);
-            let arr_ref = arr_ref.as_ref();
 
-            let sorted_array = compute::sort(arr_ref, sort_option)?;
+            let sorted_array = match &converter {
+                Some(converter) => {
+                    let rows = converter.convert_columns(&[arr_ref])?;
+                    let sorted_rows = rows.iter().sorted().collect::<Vec<_>>();
+                    match converter.convert_rows(sorted_rows)?.first() {
+                        Some(array) => array.clone(),
+                        None => {
+                            return internal_err!(
+                                "array_sort: failed to get array from rows"
+                            )
+                        }
+                    }
+                }
+                None => compute::sort(arr_ref.as_ref(), sort_option)?,
+            };
             array_lengths.push(sorted_array.len());
             arrays.push(sorted_array);
-            valid.append(true);
         }
     }
 
     // Assume all arrays have the same data type
     let data_type = list_array.value_type();
-    let buffer = valid.finish();
 
-    let elements = arrays
-        .iter()
-        .map(|a| a.as_ref())
-        .collect::<Vec<&dyn Array>>();
+    let values = if arrays.is_empty() {
+        new_empty_array(&data_type)
+    } else {
+        let elements = arrays
+            .iter()
+            .map(|a| a.as_ref())
+            .collect::<Vec<&dyn Array>>();
+        compute::concat(elements.as_slice())?
+    };
 
-    let list_arr = ListArray::new(
+    let list_arr = GenericListArray::<O>::new(
         Arc::new(Field::new("item", data_type, true)),
-        OffsetBuffer::from_lengths(array_lengths),
-        Arc::new(compute::concat(elements.as_slice())?),
-        Some(NullBuffer::new(buffer)),
+        OffsetBuffer::<O>::from_lengths(array_lengths),
+        values,
+        // The null buffer of a sliced array is sliced as well, so it lines up
+        // with the rows of the result
+        list_array.nulls().cloned(),
     );
     Ok(Arc::new(list_arr))
 }
@@ -861,10 +1669,19 @@ fn order_desc(modifier: &str) -> Result<bool> {
     match modifier.to_uppercase().as_str() {
         "DESC" => Ok(true),
         "ASC" => Ok(false),
-        _ => internal_err!("the second parameter of array_sort expects DESC or ASC"),
+        _ => internal_err!(
+            "the second parameter of array_sort expects DESC, ASC, NULLS FIRST or NULLS LAST"
+        ),
     }
 }
 
+fn is_nulls_order(modifier: &str) -> bool {
+    matches!(
+        modifier.to_uppercase().as_str(),
+        "NULLS FIRST" | "NULLS LAST"
+    )
+}
+
 fn order_nulls_first(modifier: &str) -> Result<bool> {
     match modifier.to_uppercase().as_str() {
         "NULLS FIRST" => Ok(true),
@@ -882,7 +1699,17 @@ pub fn array_prepend(args: &[ArrayRef]) -> Result<ArrayRef> {
 
     check_datatypes("array_prepend", &[element_array, list_array.values()])?;
     let res = match list_array.value_type() {
-        DataType::List(_) => concat_internal(args)?,
+        DataType::List(_) => concat_internal::<i32>(args)?,
+        // `concat_internal` only aligns `List` dimensions, a nested `LargeList`
+        // element is prepended as a single value instead
+        DataType::LargeList(_) => {
+            return general_append_and_prepend(
+                list_array,
+                element_array,
+                &list_array.value_type(),
+                false,
+            );
+        }
         DataType::Null => return make_array(&[element_array.to_owned()]),
         data_type => {
             return general_append_and_prepend(
@@ -934,14 +1761,37 @@ fn align_array_dimensions(args: Vec<ArrayRef>) -> Result<Vec<ArrayRef>> {
 }
 
 // Concatenate arrays on the same row.
-fn concat_internal(args: &[ArrayRef]) -> Result<ArrayRef> {
+/// Returns the number of rows of concatenating `list_arrays`.
+///
+/// Arrays with a single row are broadcast to the number of rows of the others.
+fn concat_row_count<O: OffsetSizeTrait>(
+    name: &str,
+    list_arrays: &[&GenericListArray<O>],
+) -> Result<usize> {
+    let row_count = list_arrays.iter().map(|arr| arr.len()).max().unwrap_or(0);
+    if let Some(arr) = list_arrays
+        .iter()
+        .find(|arr| arr.len() != 1 && arr.len() != row_count)
+    {
+        return exec_err!(
+            "{name} expects arguments with the same number of rows ({row_count}) or a single row, got {}",
+            arr.len()
+        );
+    }
+    Ok(row_count)
+}
+
+fn concat_internal<O: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     let args = align_array_dimensions(args.to_vec())?;
 
-    let list_arrays =
-        downcast_vec!(args, ListArray).collect::<Result<Vec<&ListArray>>>()?;
+    let list_arrays = args
+        .iter()
+        .map(|arg| as_generic_list_array::<O>(arg))
+        .collect::<Result<Vec<_>>>()?;
 
-    // Assume number of rows is the same for all arrays
-    let row_count = list_arrays[0].len();
+    let row_count = concat_row_count("array_concat", &list_arrays)?;
+    let row_index =
+        |arr: &GenericListArray<O>, i: usize| if arr.len() == 1 { 0 } else { i };
 
     let mut array_lengths = vec![];
     let mut arrays = vec![];
@@ -949,7 +1799,7 @@ fn concat_internal(args: &[ArrayRef]) -> Result<ArrayRef> {
     for i in 0..row_count {
         let nulls = list_arrays
             .iter()
-            .map(|arr| arr.is_null(i))
+            .map(|arr| arr.is_null(row_index(arr, i)))
             .collect::<Vec<_>>();
 
         // If all the arrays are null, the concatenated array is null
@@ -958,10 +1808,13 @@ fn concat_internal(args: &[ArrayRef]) -> Result<ArrayRef> {
             array_lengths.push(0);
             valid.append(false);
         } else {
-            // Get all the arrays on i-th row
+            // Get all the non-null arrays on i-th row, a null row may still
+            // have values behind it that must not be concatenated
             let values = list_arrays
                 .iter()
-                .map(|arr| arr.value(i))
+                .zip(&nulls)
+                .filter(|(_, &is_null)| !is_null)
+                .map(|(arr, _)| arr.value(row_index(arr, i)))
                 .collect::<Vec<_>>();
 
             let elements = values
@@ -980,35 +1833,288 @@ fn concat_internal(args: &[ArrayRef]) -> Result<ArrayRef> {
     let data_type = list_arrays[0].value_type();
     let buffer = valid.finish();
 
-    let elements = arrays
-        .iter()
-        .map(|a| a.as_ref())
-        .collect::<Vec<&dyn Array>>();
+    let values = if arrays.is_empty() {
+        new_empty_array(&data_type)
+    } else {
+        let elements = arrays
+            .iter()
+            .map(|a| a.as_ref())
+            .collect::<Vec<&dyn Array>>();
+        compute::concat(elements.as_slice())?
+    };
 
-    let list_arr = ListArray::new(
+    let list_arr = GenericListArray::<O>::new(
         Arc::new(Field::new("item", data_type, true)),
-        OffsetBuffer::from_lengths(array_lengths),
-        Arc::new(compute::concat(elements.as_slice())?),
+        OffsetBuffer::<O>::from_lengths(array_lengths),
+        values,
         Some(NullBuffer::new(buffer)),
     );
 
     Ok(Arc::new(list_arr))
 }
 
-/// Array_concat/Array_cat SQL function
-pub fn array_concat(args: &[ArrayRef]) -> Result<ArrayRef> {
-    let mut new_args = vec![];
-    for arg in args {
-        let ndim = list_ndims(arg.data_type());
-        let base_type = datafusion_common::utils::base_type(arg.data_type());
-        if ndim == 0 {
-            return not_impl_err!("Array is not type '{base_type:?}'.");
-        } else if !base_type.eq(&DataType::Null) {
-            new_args.push(arg.clone());
-        }
-    }
-
-    concat_internal(new_args.as_slice())
+/// Checks that the base element types of all list arguments are the same,
+/// ignoring arguments whose element type is `Null`.
+///
+/// On mismatch, the error lists the element type of every argument together
+/// with the (1-indexed) positions that differ from the first non-null one.
+fn check_element_datatypes(name: &str, args: &[ArrayRef]) -> Result<()> {
+    let base_types = args
+        .iter()
+        .map(|arg| concat_base_type(arg.data_type()))
+        .collect::<Vec<_>>();
+    let Some(expected) = base_types.iter().find(|dt| **dt != DataType::Null) else {
+        return Ok(());
+    };
+
+    let mismatched = base_types
+        .iter()
+        .enumerate()
+        .filter(|(_, dt)| **dt != DataType::Null && !dt.equals_datatype(expected))
+        .map(|(i, _)| i + 1)
+        .collect::<Vec<_>>();
+    if !mismatched.is_empty() {
+        return plan_err!(
+            "{name} received incompatible element types: '{base_types:?}', mismatched at positions {mismatched:?}."
+        );
+    }
+
+    Ok(())
+}
+
+/// Returns the base element type of a concatenated argument, whose outermost
+/// list may also be a `LargeList`.
+fn concat_base_type(data_type: &DataType) -> DataType {
+    match data_type {
+        DataType::LargeList(field) => {
+            datafusion_common::utils::base_type(field.data_type())
+        }
+        data_type => datafusion_common::utils::base_type(data_type),
+    }
+}
+
+/// Casts the outermost lists of the arguments of `name` to a single list type: a
+/// `LargeList` if any argument is a `LargeList`, otherwise a `List`. `FixedSizeList`
+/// arguments become variable sized lists, as the concatenated rows have different lengths.
+fn concat_args(name: &str, args: &[ArrayRef]) -> Result<Vec<ArrayRef>> {
+    let is_large = args
+        .iter()
+        .any(|arg| matches!(arg.data_type(), DataType::LargeList(_)));
+    let mut cast_args = vec![];
+    for arg in args {
+        let arg = match arg.data_type() {
+            DataType::List(field) | DataType::FixedSizeList(field, _) if is_large => {
+                compute::cast(arg, &DataType::LargeList(field.clone()))?
+            }
+            DataType::FixedSizeList(field, _) => {
+                compute::cast(arg, &DataType::List(field.clone()))?
+            }
+            DataType::List(_) | DataType::LargeList(_) => arg.clone(),
+            data_type => return not_impl_err!("Array is not type '{data_type:?}'."),
+        };
+        cast_args.push(arg);
+    }
+    check_element_datatypes(name, &cast_args)?;
+    Ok(cast_args)
+}
+
+/// Returns the arguments that are not null typed, which are skipped when concatenating.
+fn skip_null_typed(args: &[ArrayRef]) -> Vec<ArrayRef> {
+    args.iter()
+        .filter(|arg| concat_base_type(arg.data_type()) != DataType::Null)
+        .cloned()
+        .collect()
+}
+
+/// Array_concat/Array_cat SQL function
+pub fn array_concat(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let cast_args = concat_args("array_concat", args)?;
+    let new_args = skip_null_typed(&cast_args);
+
+    // Null typed arguments are skipped, but the rows and the list type are kept
+    if new_args.is_empty() {
+        return Ok(cast_args[0].clone());
+    }
+
+    match new_args[0].data_type() {
+        DataType::LargeList(_) => concat_internal::<i64>(new_args.as_slice()),
+        _ => concat_internal::<i32>(new_args.as_slice()),
+    }
+}
+
+/// Array_concat_flat SQL function
+///
+/// Flattens one level of each list of lists and concatenates the results of each row,
+/// with the same rules as [`array_concat`]. Null rows are skipped, and a row is null
+/// only if it is null in every argument.
+///
+/// For example,
+/// ```text
+/// array_concat_flat([[1, 2], [3]], [[4], [], [5]]) => [1, 2, 3, 4, 5]
+/// ```
+pub fn array_concat_flat(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let flat_args = args
+        .iter()
+        .map(|arg| match arg.data_type() {
+            DataType::List(_) => flatten_one_level::<i32>(as_list_array(arg)?),
+            DataType::LargeList(_) => flatten_one_level::<i64>(as_large_list_array(arg)?),
+            data_type => {
+                exec_err!("array_concat_flat expects lists of lists, got {data_type}")
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let cast_args = concat_args("array_concat_flat", &flat_args)?;
+    let new_args = skip_null_typed(&cast_args);
+
+    // Null typed arguments are skipped, but the rows and the list type are kept
+    if new_args.is_empty() {
+        return Ok(cast_args[0].clone());
+    }
+
+    match new_args[0].data_type() {
+        DataType::LargeList(_) => concat_internal::<i64>(new_args.as_slice()),
+        _ => concat_internal::<i32>(new_args.as_slice()),
+    }
+}
+
+/// Flattens one level of `list_array`, keeping its offset size and null rows.
+///
+/// A list of null typed elements has nothing to flatten and is returned as is.
+fn flatten_one_level<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
+) -> Result<ArrayRef> {
+    match list_array.value_type() {
+        DataType::List(_) => {
+            compose_list_offsets(list_array, as_list_array(list_array.values())?)
+        }
+        DataType::LargeList(_) => {
+            compose_list_offsets(list_array, as_large_list_array(list_array.values())?)
+        }
+        DataType::Null => Ok(Arc::new(list_array.clone())),
+        data_type => exec_err!(
+            "array_concat_flat expects lists of lists, got a list of {data_type}"
+        ),
+    }
+}
+
+/// Returns the rows of `outer` as lists of the elements of their `inner` lists.
+fn compose_list_offsets<O: OffsetSizeTrait, I: OffsetSizeTrait>(
+    outer: &GenericListArray<O>,
+    inner: &GenericListArray<I>,
+) -> Result<ArrayRef> {
+    let inner_offsets = inner.offsets();
+    let offsets = outer
+        .offsets()
+        .iter()
+        .map(|offset| {
+            let offset = inner_offsets[offset.as_usize()].as_usize();
+            O::from_usize(offset).ok_or_else(|| {
+                DataFusionError::Execution(format!(
+                    "array_concat_flat offset {offset} overflows the list offset type"
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let field = match inner.data_type() {
+        DataType::List(field) | DataType::LargeList(field) => field.clone(),
+        data_type => {
+            return internal_err!("array_concat_flat got unexpected type {data_type:?}")
+        }
+    };
+
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        field,
+        OffsetBuffer::new(offsets.into()),
+        inner.values().clone(),
+        outer.nulls().cloned(),
+    )?))
+}
+
+/// Array_concat_distinct SQL function
+///
+/// Concatenates the arrays of each row and removes duplicates, keeping the first
+/// occurrence of each element in order. Duplicates are skipped while copying, so the
+/// concatenated array is never materialized.
+///
+/// For example,
+/// ```text
+/// array_concat_distinct([1, 2, 1], [3, 2], [4, 3]) => [1, 2, 3, 4]
+/// ```
+pub fn array_concat_distinct(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let cast_args = concat_args("array_concat_distinct", args)?;
+    let new_args = skip_null_typed(&cast_args);
+
+    // Null typed arguments are skipped, but the rows and the list type are kept
+    if new_args.is_empty() {
+        return Ok(cast_args[0].clone());
+    }
+
+    let args = align_array_dimensions(new_args)?;
+    match args[0].data_type() {
+        DataType::LargeList(_) => general_concat_distinct::<i64>(&args),
+        _ => general_concat_distinct::<i32>(&args),
+    }
+}
+
+fn general_concat_distinct<O: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let list_arrays = args
+        .iter()
+        .map(|arg| as_generic_list_array::<O>(arg))
+        .collect::<Result<Vec<_>>>()?;
+    let row_count = concat_row_count("array_concat_distinct", &list_arrays)?;
+    let row_index =
+        |arr: &GenericListArray<O>, i: usize| if arr.len() == 1 { 0 } else { i };
+
+    let data_type = list_arrays[0].value_type();
+    let converter = RowConverter::new(vec![SortField::new(data_type.clone())])?;
+    let values = list_arrays
+        .iter()
+        .map(|arr| converter.convert_columns(&[arr.values().clone()]))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let values_data = list_arrays
+        .iter()
+        .map(|arr| arr.values().to_data())
+        .collect::<Vec<_>>();
+    let capacity = Capacities::Array(values_data.iter().map(|data| data.len()).sum());
+    let mut mutable =
+        MutableArrayData::with_capacities(values_data.iter().collect(), false, capacity);
+
+    let mut offsets = Vec::with_capacity(row_count + 1);
+    offsets.push(O::usize_as(0));
+    let mut valid = BooleanBufferBuilder::new(row_count);
+    for i in 0..row_count {
+        // If all the arrays are null, the concatenated array is null
+        if list_arrays.iter().all(|arr| arr.is_null(row_index(arr, i))) {
+            offsets.push(O::usize_as(mutable.len()));
+            valid.append(false);
+            continue;
+        }
+
+        let mut seen = HashSet::new();
+        for (array_index, (arr, values)) in list_arrays.iter().zip(&values).enumerate() {
+            let row = row_index(arr, i);
+            if arr.is_null(row) {
+                continue;
+            }
+            let start = arr.value_offsets()[row].as_usize();
+            let end = arr.value_offsets()[row + 1].as_usize();
+            for value_index in start..end {
+                if seen.insert(values.row(value_index)) {
+                    mutable.extend(array_index, value_index, value_index + 1);
+                }
+            }
+        }
+        offsets.push(O::usize_as(mutable.len()));
+        valid.append(true);
+    }
+
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        Arc::new(Field::new("item", data_type, true)),
+        OffsetBuffer::new(offsets.into()),
+        arrow_array::make_array(mutable.freeze()),
+        Some(NullBuffer::new(valid.finish())),
+    )?))
 }
 
 /// Array_empty SQL function
@@ -1036,6 +2142,23 @@ fn array_empty_dispatch<O: OffsetSizeTrait>(array: &ArrayRef) -> Result<ArrayRef
 }
 
 /// Array_repeat SQL function
+///
+/// The repeat is element-wise: each row's `element` is repeated by that row's
+/// `count`, producing one list per input row. Rows therefore end up with
+/// lists of different lengths, and a list `element` is repeated as a whole
+/// rather than having its items repeated.
+///
+/// For example, with one row per element,
+/// ```text
+/// array_repeat(
+///     1, 2 => [1, 1]
+///     2, 0 => []
+///     3, 1 => [3]
+/// )
+/// array_repeat(
+///     [1, 2], 2 => [[1, 2], [1, 2]]
+/// )
+/// ```
 pub fn array_repeat(args: &[ArrayRef]) -> Result<ArrayRef> {
     let element = &args[0];
     let count_array = as_int64_array(&args[1])?;
@@ -1043,7 +2166,16 @@ pub fn array_repeat(args: &[ArrayRef]) -> Result<ArrayRef> {
     match element.data_type() {
         DataType::List(_) => {
             let list_array = as_list_array(element)?;
-            general_list_repeat(list_array, count_array)
+            general_list_repeat::<i32>(list_array, count_array)
+        }
+        DataType::LargeList(_) => {
+            let list_array = as_large_list_array(element)?;
+            general_list_repeat::<i64>(list_array, count_array)
+        }
+        // A fixed size list is repeated as a list of its elements, giving `List<List>`
+        DataType::FixedSizeList(field, _) => {
+            let element = compute::cast(element, &DataType::List(field.clone()))?;
+            general_list_repeat::<i32>(as_list_array(&element)?, count_array)
         }
         _ => general_repeat(element, count_array),
     }
@@ -1055,6 +2187,8 @@ pub fn array_repeat(args: &[ArrayRef]) -> Result<ArrayRef> {
 ///     1. `count[i] >= 0`
 ///     2. `array.len() == count_array.len()`
 ///
+/// A count of 0 produces an empty list rather than a null row.
+///
 /// For example,
 /// ```text
 /// array_repeat(
@@ -1102,9 +2236,10 @@ fn general_repeat(array: &ArrayRef, count_array: &Int64Array) -> Result<ArrayRef
     )?))
 }
 
-/// Handle List version of `general_repeat`
+/// Handle List and LargeList version of `general_repeat`
 ///
 /// For each element of `list_array[i]` repeat `count_array[i]` times.
+/// As in `general_repeat`, a count of 0 produces an empty list.
 ///
 /// For example,
 /// ```text
@@ -1112,8 +2247,8 @@ fn general_repeat(array: &ArrayRef, count_array: &Int64Array) -> Result<ArrayRef
 ///     [[1, 2, 3], [4, 5], [6]], [2, 0, 1] => [[[1, 2, 3], [1, 2, 3]], [], [[6]]]
 /// )
 /// ```
-fn general_list_repeat(
-    list_array: &ListArray,
+fn general_list_repeat<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
     count_array: &Int64Array,
 ) -> Result<ArrayRef> {
     let data_type = list_array.data_type();
@@ -1145,9 +2280,9 @@ fn general_list_repeat(
                 let data = mutable.freeze();
                 let repeated_array = arrow_array::make_array(data);
 
-                let list_arr = ListArray::try_new(
+                let list_arr = GenericListArray::<O>::try_new(
                     Arc::new(Field::new("item", value_type.clone(), true)),
-                    OffsetBuffer::from_lengths(vec![original_data.len(); count]),
+                    OffsetBuffer::<O>::from_lengths(vec![original_data.len(); count]),
                     repeated_array,
                     None,
                 )?;
@@ -1162,7 +2297,7 @@ fn general_list_repeat(
     let new_values: Vec<_> = new_values.iter().map(|a| a.as_ref()).collect();
     let values = compute::concat(&new_values)?;
 
-    Ok(Arc::new(ListArray::try_new(
+    Ok(Arc::new(GenericListArray::<O>::try_new(
         Arc::new(Field::new("item", data_type.to_owned(), true)),
         OffsetBuffer::from_lengths(lengths),
         values,
@@ -1172,7 +2307,17 @@ fn general_list_repeat(
 
 /// Array_position SQL function
 pub fn array_position(args: &[ArrayRef]) -> Result<ArrayRef> {
-    let list_array = as_list_array(&args[0])?;
+    match &args[0].data_type() {
+        DataType::List(_) => general_position_dispatch::<i32>(args),
+        DataType::LargeList(_) => general_position_dispatch::<i64>(args),
+        array_type => {
+            not_impl_err!("array_position does not support type '{array_type:?}'.")
+        }
+    }
+}
+
+fn general_position_dispatch<O: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let list_array = as_generic_list_array::<O>(&args[0])?;
     let element_array = &args[1];
 
     check_datatypes("array_position", &[list_array.values(), element_array])?;
@@ -1199,7 +2344,7 @@ pub fn array_position(args: &[ArrayRef]) -> Result<ArrayRef> {
         }
     }
 
-    general_position::<i32>(list_array, element_array, arr_from)
+    general_position::<O>(list_array, element_array, arr_from)
 }
 
 fn general_position<OffsetSize: OffsetSizeTrait>(
@@ -1234,6 +2379,62 @@ fn general_position<OffsetSize: OffsetSizeTrait>(
     Ok(Arc::new(UInt64Array::from(data)))
 }
 
+/// Array_position_from_end SQL function
+///
+/// Returns the 1-indexed position of the last occurrence of `element` in each row,
+/// or null if the element is not found.
+///
+/// For example,
+/// ```text
+/// array_position_from_end([1, 2, 2, 3, 1, 4], 2) => 3
+/// ```
+pub fn array_position_from_end(args: &[ArrayRef]) -> Result<ArrayRef> {
+    match &args[0].data_type() {
+        DataType::List(_) => {
+            general_position_from_end::<i32>(as_list_array(&args[0])?, &args[1])
+        }
+        DataType::LargeList(_) => {
+            general_position_from_end::<i64>(as_large_list_array(&args[0])?, &args[1])
+        }
+        array_type => {
+            not_impl_err!(
+                "array_position_from_end does not support type '{array_type:?}'."
+            )
+        }
+    }
+}
+
+fn general_position_from_end<OffsetSize: OffsetSizeTrait>(
+    list_array: &GenericListArray<OffsetSize>,
+    element_array: &ArrayRef,
+) -> Result<ArrayRef> {
+    check_datatypes(
+        "array_position_from_end",
+        &[list_array.values(), element_array],
+    )?;
+
+    let mut data = Vec::with_capacity(list_array.len());
+
+    for (row_index, list_array_row) in list_array.iter().enumerate() {
+        if let Some(list_array_row) = list_array_row {
+            let eq_array =
+                compare_element_to_list(&list_array_row, element_array, row_index, true)?;
+
+            // Scan from the end for the last `true`, in 1-indexed position
+            let index = eq_array
+                .iter()
+                .rposition(|e| e == Some(true))
+                .map(|index| index as u64 + 1);
+
+            data.push(index);
+        } else {
+            data.push(None);
+        }
+    }
+
+    Ok(Arc::new(UInt64Array::from(data)))
+}
+
 /// Array_positions SQL function
 pub fn array_positions(args: &[ArrayRef]) -> Result<ArrayRef> {
     let arr = as_list_array(&args[0])?;
@@ -1297,9 +2498,8 @@ fn general_remove<OffsetSize: OffsetSizeTrait>(
 ) -> Result<ArrayRef> {
     let data_type = list_array.value_type();
     let mut new_values = vec![];
-    // Build up the offsets for the final output array
-    let mut offsets = Vec::<OffsetSize>::with_capacity(arr_n.len() + 1);
-    offsets.push(OffsetSize::zero());
+    // Build up the row lengths for the final output array
+    let mut lengths = Vec::with_capacity(arr_n.len());
 
     // n is the number of elements to remove in this row
     for (row_index, (list_array_row, n)) in
@@ -1338,14 +2538,12 @@ fn general_remove<OffsetSize: OffsetSizeTrait>(
                 };
 
                 let filtered_array = arrow::compute::filter(&list_array_row, &eq_array)?;
-                offsets.push(
-                    offsets[row_index] + OffsetSize::usize_as(filtered_array.len()),
-                );
+                lengths.push(filtered_array.len());
                 new_values.push(filtered_array);
             }
             None => {
-                // Null element results in a null row (no new offsets)
-                offsets.push(offsets[row_index]);
+                // Null element results in a null row (no new values)
+                lengths.push(0);
             }
         }
     }
@@ -1359,7 +2557,7 @@ fn general_remove<OffsetSize: OffsetSizeTrait>(
 
     Ok(Arc::new(GenericListArray::<OffsetSize>::try_new(
         Arc::new(Field::new("item", data_type, true)),
-        OffsetBuffer::new(offsets.into()),
+        OffsetBuffer::from_lengths(lengths),
         values,
         list_array.nulls().cloned(),
     )?))
@@ -1394,12 +2592,97 @@ pub fn array_remove(args: &[ArrayRef]) -> Result<ArrayRef> {
 }
 
 pub fn array_remove_n(args: &[ArrayRef]) -> Result<ArrayRef> {
-    let arr_n = as_int64_array(&args[2])?.values().to_vec();
+    let n_array = as_int64_array(&args[2])?;
+    let num_rows = args[0].len();
+    // A single row `n` is broadcast to every row of the array
+    let arr_n = match n_array.len() {
+        1 => vec![n_array.value(0); num_rows],
+        len if len == num_rows => n_array.values().to_vec(),
+        len => {
+            return exec_err!(
+                "array_remove_n expects n with the same number of rows as the array ({num_rows}) or a single row, got {len}"
+            )
+        }
+    };
     array_remove_internal(&args[0], &args[1], arr_n)
 }
 
+/// For each element of `list_array[i]`, removes the null elements, keeping the
+/// order of the other elements.
+///
+/// ```text
+/// general_compact(
+///   [1, NULL, 2, NULL]  ==> [1, 2]
+///   [NULL, NULL]        ==> []
+///   NULL                ==> NULL
+/// )
+/// ```
+fn general_compact<OffsetSize: OffsetSizeTrait>(
+    list_array: &GenericListArray<OffsetSize>,
+    field: &FieldRef,
+) -> Result<ArrayRef> {
+    let mut new_values = vec![];
+    // Build up the row lengths for the final output array
+    let mut lengths = Vec::with_capacity(list_array.len());
+
+    for list_array_row in list_array.iter() {
+        match list_array_row {
+            // Logical nulls also cover null typed elements, which have no validity buffer
+            Some(list_array_row) if list_array_row.logical_nulls().is_some() => {
+                let is_valid = compute::is_not_null(&list_array_row)?;
+                let filtered_array = compute::filter(&list_array_row, &is_valid)?;
+                lengths.push(filtered_array.len());
+                new_values.push(filtered_array);
+            }
+            Some(list_array_row) => {
+                lengths.push(list_array_row.len());
+                new_values.push(list_array_row);
+            }
+            None => {
+                // Null element results in a null row (no new values)
+                lengths.push(0);
+            }
+        }
+    }
+
+    let values = if new_values.is_empty() {
+        new_empty_array(field.data_type())
+    } else {
+        let new_values = new_values.iter().map(|x| x.as_ref()).collect::<Vec<_>>();
+        compute::concat(&new_values)?
+    };
+
+    Ok(Arc::new(GenericListArray::<OffsetSize>::try_new(
+        field.clone(),
+        OffsetBuffer::from_lengths(lengths),
+        values,
+        list_array.nulls().cloned(),
+    )?))
+}
+
+/// Array_compact SQL function
+///
+/// Removes the null elements of each array. An array of only null elements becomes
+/// an empty array, and a null array stays null.
+pub fn array_compact(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if args.len() != 1 {
+        return exec_err!("array_compact needs one argument");
+    }
+
+    match args[0].data_type() {
+        DataType::List(field) => general_compact::<i32>(args[0].as_list::<i32>(), field),
+        DataType::LargeList(field) => {
+            general_compact::<i64>(args[0].as_list::<i64>(), field)
+        }
+        DataType::Null => Ok(args[0].clone()),
+        data_type => {
+            not_impl_err!("array_compact does not support type '{data_type:?}'.")
+        }
+    }
+}
+
 /// For each element of `list_array[i]`, replaces up to `arr_n[i]`  occurences
-/// of `from_array[i]`, `to_array[i]`.
+/// of `from_array[i]`, `to_array[i]`. A single `arr_n` is used for every row.
 ///
 /// The type of each **element** in `list_array` must be the same as the type of
 /// `from_array` and `to_array`. This function also handles nested arrays
@@ -1421,8 +2704,16 @@ fn general_replace(
     to_array: &ArrayRef,
     arr_n: Vec<i64>,
 ) -> Result<ArrayRef> {
-    // Build up the offsets for the final output array
-    let mut offsets: Vec<i32> = vec![0];
+    let num_rows = list_array.len();
+    if arr_n.len() != 1 && arr_n.len() != num_rows {
+        return exec_err!(
+            "array_replace_n expects n with the same number of rows as the array ({num_rows}) or a single row, got {}",
+            arr_n.len()
+        );
+    }
+
+    // Build up the row lengths for the final output array
+    let mut lengths = Vec::with_capacity(list_array.len());
     let values = list_array.values();
     let original_data = values.to_data();
     let to_data = to_array.to_data();
@@ -1439,7 +2730,7 @@ fn general_replace(
 
     for (row_index, offset_window) in list_array.offsets().windows(2).enumerate() {
         if list_array.is_null(row_index) {
-            offsets.push(offsets[row_index]);
+            lengths.push(0);
             valid.append(false);
             continue;
         }
@@ -1456,13 +2747,18 @@ fn general_replace(
 
         let original_idx = 0;
         let replace_idx = 1;
-        let n = arr_n[row_index];
+        // A single row `n` is broadcast to every row of the array
+        let n = if arr_n.len() == 1 {
+            arr_n[0]
+        } else {
+            arr_n[row_index]
+        };
         let mut counter = 0;
 
         // All elements are false, no need to replace, just copy original data
         if eq_array.false_count() == eq_array.len() {
             mutable.extend(original_idx, start, end);
-            offsets.push(offsets[row_index] + (end - start) as i32);
+            lengths.push(end - start);
             valid.append(true);
             continue;
         }
@@ -1482,7 +2778,7 @@ fn general_replace(
             }
         }
 
-        offsets.push(offsets[row_index] + (end - start) as i32);
+        lengths.push(end - start);
         valid.append(true);
     }
 
@@ -1490,7 +2786,7 @@ fn general_replace(
 
     Ok(Arc::new(ListArray::try_new(
         Arc::new(Field::new("item", list_array.value_type(), true)),
-        OffsetBuffer::new(offsets.into()),
+        OffsetBuffer::from_lengths(lengths),
         arrow_array::make_array(data),
         Some(NullBuffer::new(valid.finish())),
     )?))
@@ -1514,28 +2810,104 @@ pub fn array_replace_all(args: &[ArrayRef]) -> Result<ArrayRef> {
     general_replace(as_list_array(&args[0])?, &args[1], &args[2], arr_n)
 }
 
-macro_rules! to_string {
-    ($ARG:expr, $ARRAY:expr, $DELIMITER:expr, $NULL_STRING:expr, $WITH_NULL_STRING:expr, $ARRAY_TYPE:ident) => {{
-        let arr = downcast_arg!($ARRAY, $ARRAY_TYPE);
-        for x in arr {
-            match x {
-                Some(x) => {
-                    $ARG.push_str(&x.to_string());
-                    $ARG.push_str($DELIMITER);
-                }
-                None => {
-                    if $WITH_NULL_STRING {
-                        $ARG.push_str($NULL_STRING);
-                        $ARG.push_str($DELIMITER);
-                    }
-                }
-            }
-        }
-        Ok($ARG)
-    }};
-}
-
-fn union_generic_lists<OffsetSize: OffsetSizeTrait>(
+/// Array_reverse SQL function
+///
+/// Reverses the order of the elements of each row, null rows stay null.
+///
+/// For example,
+/// ```text
+/// array_reverse([1, 2, 3]) => [3, 2, 1]
+/// ```
+pub fn array_reverse(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if args.len() != 1 {
+        return exec_err!("array_reverse needs one argument");
+    }
+
+    match &args[0].data_type() {
+        DataType::List(field) => {
+            let array = as_list_array(&args[0])?;
+            general_array_reverse::<i32>(array, field)
+        }
+        DataType::LargeList(field) => {
+            let array = as_large_list_array(&args[0])?;
+            general_array_reverse::<i64>(array, field)
+        }
+        DataType::Null => Ok(args[0].clone()),
+        array_type => {
+            not_impl_err!("array_reverse does not support type '{array_type:?}'.")
+        }
+    }
+}
+
+fn general_array_reverse<O: OffsetSizeTrait>(
+    array: &GenericListArray<O>,
+    field: &FieldRef,
+) -> Result<ArrayRef> {
+    let values = array.values();
+    let original_data = values.to_data();
+    let capacity = Capacities::Array(original_data.len());
+    let mut lengths = Vec::with_capacity(array.len());
+    let mut mutable =
+        MutableArrayData::with_capacities(vec![&original_data], false, capacity);
+
+    // The offsets of a sliced array index into its unsliced values, so each row is
+    // read through its own offsets rather than by position in `values`
+    for (row_index, offset_window) in array.offsets().windows(2).enumerate() {
+        if array.is_null(row_index) {
+            lengths.push(0);
+            continue;
+        }
+
+        let start = offset_window[0].as_usize();
+        let end = offset_window[1].as_usize();
+        for index in (start..end).rev() {
+            mutable.extend(0, index, index + 1);
+        }
+        lengths.push(end - start);
+    }
+
+    let data = mutable.freeze();
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        field.clone(),
+        OffsetBuffer::<O>::from_lengths(lengths),
+        arrow_array::make_array(data),
+        array.nulls().cloned(),
+    )?))
+}
+
+macro_rules! to_string {
+    ($ARG:expr, $ARRAY:expr, $DELIMITER:expr, $NULL_STRING:expr, $WITH_NULL_STRING:expr, $ARRAY_TYPE:ident) => {{
+        to_string!(
+            $ARG,
+            $ARRAY,
+            $DELIMITER,
+            $NULL_STRING,
+            $WITH_NULL_STRING,
+            $ARRAY_TYPE,
+            |x| x.to_string()
+        )
+    }};
+    ($ARG:expr, $ARRAY:expr, $DELIMITER:expr, $NULL_STRING:expr, $WITH_NULL_STRING:expr, $ARRAY_TYPE:ident, |$X:ident| $FORMAT:expr) => {{
+        let arr = downcast_arg!($ARRAY, $ARRAY_TYPE);
+        for x in arr {
+            match x {
+                Some($X) => {
+                    $ARG.push_str(&$FORMAT);
+                    $ARG.push_str($DELIMITER);
+                }
+                None => {
+                    if $WITH_NULL_STRING {
+                        $ARG.push_str($NULL_STRING);
+                        $ARG.push_str($DELIMITER);
+                    }
+                }
+            }
+        }
+        Ok($ARG)
+    }};
+}
+
+fn union_generic_lists<OffsetSize: OffsetSizeTrait>(
     l: &GenericListArray<OffsetSize>,
     r: &GenericListArray<OffsetSize>,
     field: &FieldRef,
@@ -1584,6 +2956,10 @@ fn union_generic_lists<OffsetSize: OffsetSizeTrait>(
 }
 
 /// Array_union SQL function
+///
+/// The inner field of the result is taken from the left argument. The inner
+/// fields of both arguments may differ in name or metadata, but their data
+/// types must match.
 pub fn array_union(args: &[ArrayRef]) -> Result<ArrayRef> {
     if args.len() != 2 {
         return exec_err!("array_union needs two arguments");
@@ -1601,6 +2977,7 @@ pub fn array_union(args: &[ArrayRef]) -> Result<ArrayRef> {
             (DataType::Null, _) => Ok(array2.clone()),
             (_, DataType::Null) => Ok(array1.clone()),
             (_, _) => {
+                check_datatypes("array_union", &[array1, array2])?;
                 let list1 = array1.as_list::<O>();
                 let list2 = array2.as_list::<O>();
                 let result = union_generic_lists::<O>(list1, list2, l_field_ref)?;
@@ -1612,6 +2989,9 @@ pub fn array_union(args: &[ArrayRef]) -> Result<ArrayRef> {
     match (array1.data_type(), array2.data_type()) {
         (DataType::Null, _) => Ok(array2.clone()),
         (_, DataType::Null) => Ok(array1.clone()),
+        (DataType::FixedSizeList(_, _), _) | (_, DataType::FixedSizeList(_, _)) => {
+            not_impl_err!("array_union does not support FixedSizeList arguments")
+        }
         (DataType::List(l_field_ref), DataType::List(r_field_ref)) => {
             union_arrays::<i32>(array1, array2, l_field_ref, r_field_ref)
         }
@@ -1627,25 +3007,78 @@ pub fn array_union(args: &[ArrayRef]) -> Result<ArrayRef> {
 }
 
 /// Array_to_string SQL function
+///
+/// A null array produces null, while an empty array produces an empty string.
+///
+/// The optional fourth argument is the number of decimal places of floating point
+/// elements, e.g. array_to_string(\[1.5, 0.126], ',', NULL, 2) -> '1.50,0.13'.
 pub fn array_to_string(args: &[ArrayRef]) -> Result<ArrayRef> {
     let arr = &args[0];
 
-    let delimiters = as_string_array(&args[1])?;
-    let delimiters: Vec<Option<&str>> = delimiters.iter().collect();
-
-    let mut null_string = String::from("");
-    let mut with_null_string = false;
-    if args.len() == 3 {
-        null_string = as_string_array(&args[2])?.value(0).to_string();
-        with_null_string = true;
+    fn collect_strings(array: &ArrayRef) -> Result<Vec<Option<&str>>> {
+        match array.data_type() {
+            DataType::Null => Ok(vec![None; array.len()]),
+            DataType::LargeUtf8 => {
+                Ok(as_generic_string_array::<i64>(array)?.iter().collect())
+            }
+            _ => Ok(as_generic_string_array::<i32>(array)?.iter().collect()),
+        }
     }
 
+    let delimiters = collect_strings(&args[1])?;
+
+    // The null replacement is evaluated per row, a single row is used for every row.
+    // A NULL replacement omits null elements.
+    let null_strings: Vec<Option<&str>> = match args.get(2) {
+        Some(null_strings) => {
+            let null_strings = collect_strings(null_strings)?;
+            if null_strings.len() != 1 && null_strings.len() != arr.len() {
+                return exec_err!(
+                    "array_to_string expects a null string with the same number of rows as the array ({}) or a single row, got {}",
+                    arr.len(),
+                    null_strings.len()
+                );
+            }
+            null_strings
+        }
+        None => vec![],
+    };
+    let null_string_at = |row_index: usize| -> (String, bool) {
+        let row_index = if null_strings.len() == 1 {
+            0
+        } else {
+            row_index
+        };
+        match null_strings.get(row_index).copied().flatten() {
+            Some(null_string) => (null_string.to_string(), true),
+            None => (String::from(""), false),
+        }
+    };
+
+    // The precision is evaluated per row. A NULL precision keeps the default formatting.
+    let precisions = args.get(3).map(|arg| as_int64_array(arg)).transpose()?;
+    let precision_at = |row_index: usize| -> Result<Option<usize>> {
+        match precisions {
+            Some(precisions) if precisions.is_valid(row_index) => {
+                let precision = precisions.value(row_index);
+                match usize::try_from(precision) {
+                    Ok(precision) => Ok(Some(precision)),
+                    Err(_) => exec_err!(
+                        "array_to_string precision must not be negative, got {precision}"
+                    ),
+                }
+            }
+            _ => Ok(None),
+        }
+    };
+
     fn compute_array_to_string(
         arg: &mut String,
         arr: ArrayRef,
         delimiter: String,
         null_string: String,
         with_null_string: bool,
+        precision: Option<usize>,
     ) -> Result<&mut String> {
         match arr.data_type() {
             DataType::List(..) => {
@@ -1658,12 +3091,79 @@ pub fn array_to_string(args: &[ArrayRef]) -> Result<ArrayRef> {
                         delimiter.clone(),
                         null_string.clone(),
                         with_null_string,
+                        precision,
+                    )?;
+                }
+
+                Ok(arg)
+            }
+            DataType::FixedSizeList(..) => {
+                let list_array = downcast_arg!(arr, FixedSizeListArray);
+
+                for i in 0..list_array.len() {
+                    compute_array_to_string(
+                        arg,
+                        list_array.value(i),
+                        delimiter.clone(),
+                        null_string.clone(),
+                        with_null_string,
+                        precision,
                     )?;
                 }
 
                 Ok(arg)
             }
             DataType::Null => Ok(arg),
+            // Dictionary elements are stringified by their decoded values
+            DataType::Dictionary(_, value_type) => {
+                let arr = compute::cast(&arr, value_type)?;
+                compute_array_to_string(
+                    arg,
+                    arr,
+                    delimiter,
+                    null_string,
+                    with_null_string,
+                    precision,
+                )
+            }
+            DataType::Float32 | DataType::Float64 if precision.is_some() => {
+                let precision = precision.unwrap_or_default();
+                macro_rules! array_function {
+                    ($ARRAY_TYPE:ident) => {
+                        to_string!(
+                            arg,
+                            arr,
+                            &delimiter,
+                            &null_string,
+                            with_null_string,
+                            $ARRAY_TYPE,
+                            |x| format!("{x:.precision$}")
+                        )
+                    };
+                }
+                call_array_function!(arr.data_type(), false)
+            }
+            // Formatted the same as a cast to string, e.g. decimals keep their scale
+            // and timestamps with a timezone keep their offset
+            DataType::Decimal128(_, _)
+            | DataType::Decimal256(_, _)
+            | DataType::Date32
+            | DataType::Date64
+            | DataType::Time32(_)
+            | DataType::Time64(_)
+            | DataType::Timestamp(_, _)
+            | DataType::Duration(_)
+            | DataType::Interval(_) => {
+                let arr = compute::cast(&arr, &DataType::Utf8)?;
+                to_string!(
+                    arg,
+                    arr,
+                    &delimiter,
+                    &null_string,
+                    with_null_string,
+                    StringArray
+                )
+            }
             data_type => {
                 macro_rules! array_function {
                     ($ARRAY_TYPE:ident) => {
@@ -1687,16 +3187,26 @@ pub fn array_to_string(args: &[ArrayRef]) -> Result<ArrayRef> {
 
     match arr.data_type() {
         DataType::List(_) | DataType::LargeList(_) | DataType::FixedSizeList(_, _) => {
-            let list_array = arr.as_list::<i32>();
-            for (arr, &delimiter) in list_array.iter().zip(delimiters.iter()) {
+            let rows: Vec<Option<ArrayRef>> = match arr.data_type() {
+                DataType::LargeList(_) => arr.as_list::<i64>().iter().collect(),
+                DataType::FixedSizeList(_, _) => {
+                    arr.as_fixed_size_list().iter().collect()
+                }
+                _ => arr.as_list::<i32>().iter().collect(),
+            };
+            for (row_index, (arr, &delimiter)) in
+                rows.into_iter().zip(delimiters.iter()).enumerate()
+            {
                 if let (Some(arr), Some(delimiter)) = (arr, delimiter) {
                     arg = String::from("");
+                    let (null_string, with_null_string) = null_string_at(row_index);
                     let s = compute_array_to_string(
                         &mut arg,
                         arr,
                         delimiter.to_string(),
-                        null_string.clone(),
+                        null_string,
                         with_null_string,
+                        precision_at(row_index)?,
                     )?
                     .clone();
 
@@ -1710,16 +3220,20 @@ pub fn array_to_string(args: &[ArrayRef]) -> Result<ArrayRef> {
                 }
             }
         }
+        // A null array is null, unlike an empty array which is an empty string
+        DataType::Null => res.extend(std::iter::repeat(None).take(arr.len())),
         _ => {
             // delimiter length is 1
             assert_eq!(delimiters.len(), 1);
             let delimiter = delimiters[0].unwrap();
+            let (null_string, with_null_string) = null_string_at(0);
             let s = compute_array_to_string(
                 &mut arg,
                 arr.clone(),
                 delimiter.to_string(),
                 null_string,
                 with_null_string,
+                precision_at(0)?,
             )?
             .clone();
 
@@ -1737,8 +3251,24 @@ pub fn array_to_string(args: &[ArrayRef]) -> Result<ArrayRef> {
 
 /// Cardinality SQL function
 pub fn cardinality(args: &[ArrayRef]) -> Result<ArrayRef> {
-    let list_array = as_list_array(&args[0])?.clone();
+    match &args[0].data_type() {
+        DataType::List(_) => {
+            let list_array = as_list_array(&args[0])?;
+            generic_list_cardinality::<i32>(list_array)
+        }
+        DataType::LargeList(_) => {
+            let list_array = as_large_list_array(&args[0])?;
+            generic_list_cardinality::<i64>(list_array)
+        }
+        array_type => {
+            not_impl_err!("cardinality does not support type '{array_type:?}'.")
+        }
+    }
+}
 
+fn generic_list_cardinality<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
+) -> Result<ArrayRef> {
     let result = list_array
         .iter()
         .map(|arr| match compute_array_dims(arr)? {
@@ -1827,6 +3357,279 @@ pub fn array_length(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 
+/// Array_distance SQL function
+///
+/// Returns the Euclidean distance between the rows of two lists of numbers, which
+/// must have the same length. A null row or a null element gives a null distance.
+///
+/// For example,
+/// ```text
+/// array_distance([1, 2], [4, 6]) => 5.0
+/// ```
+pub fn array_distance(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if args.len() != 2 {
+        return exec_err!("array_distance needs two arguments");
+    }
+
+    match (args[0].data_type(), args[1].data_type()) {
+        (DataType::List(_), DataType::List(_)) => general_array_distance::<i32>(args),
+        (
+            DataType::List(_) | DataType::LargeList(_),
+            DataType::List(_) | DataType::LargeList(_),
+        ) => {
+            // Mixed offset sizes are compared as LargeList
+            let args = args
+                .iter()
+                .map(|arg| match arg.data_type() {
+                    DataType::List(field) => {
+                        Ok(compute::cast(arg, &DataType::LargeList(field.clone()))?)
+                    }
+                    _ => Ok(arg.clone()),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            general_array_distance::<i64>(&args)
+        }
+        (lhs, rhs) => {
+            exec_err!("array_distance does not support types '{lhs:?}' and '{rhs:?}'")
+        }
+    }
+}
+
+fn general_array_distance<O: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let lhs = as_generic_list_array::<O>(&args[0])?;
+    let rhs = as_generic_list_array::<O>(&args[1])?;
+    if lhs.len() != rhs.len() {
+        return exec_err!(
+            "array_distance expects arguments with the same number of rows, got {} and {}",
+            lhs.len(),
+            rhs.len()
+        );
+    }
+
+    // The elements of any numeric type are cast to f64 once, for all rows
+    let to_f64 = |array: &ArrayRef| -> Result<ArrayRef> {
+        // empty arrays have null elements
+        if !array.data_type().is_numeric() && array.data_type() != &DataType::Null {
+            return exec_err!(
+                "array_distance expects lists of numbers, got elements of type {}",
+                array.data_type()
+            );
+        }
+        Ok(compute::cast(array, &DataType::Float64)?)
+    };
+    let lhs_values = to_f64(lhs.values())?;
+    let lhs_values = as_float64_array(&lhs_values)?;
+    let rhs_values = to_f64(rhs.values())?;
+    let rhs_values = as_float64_array(&rhs_values)?;
+
+    let mut builder = Float64Builder::with_capacity(lhs.len());
+    for (row_index, (lhs_window, rhs_window)) in lhs
+        .offsets()
+        .windows(2)
+        .zip(rhs.offsets().windows(2))
+        .enumerate()
+    {
+        if lhs.is_null(row_index) || rhs.is_null(row_index) {
+            builder.append_null();
+            continue;
+        }
+
+        let lhs_range = lhs_window[0].as_usize()..lhs_window[1].as_usize();
+        let rhs_range = rhs_window[0].as_usize()..rhs_window[1].as_usize();
+        if lhs_range.len() != rhs_range.len() {
+            return exec_err!(
+                "array_distance expects arrays of the same length, got {} and {}",
+                lhs_range.len(),
+                rhs_range.len()
+            );
+        }
+
+        let distance = lhs_range
+            .zip(rhs_range)
+            .map(|(i, j)| {
+                (lhs_values.is_valid(i) && rhs_values.is_valid(j)).then(|| {
+                    let diff = lhs_values.value(i) - rhs_values.value(j);
+                    diff * diff
+                })
+            })
+            .sum::<Option<f64>>()
+            .map(f64::sqrt);
+        builder.append_option(distance);
+    }
+
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Array_sum SQL function
+///
+/// Returns the sum of the non-null elements of each row, with the same result
+/// type as the `SUM` aggregate, e.g. Int64 for integers and Float64 for floats.
+/// A null row, or a row without non-null elements, gives a null sum.
+///
+/// For example,
+/// ```text
+/// array_sum([1, NULL, 2]) => 3
+/// ```
+pub fn array_sum(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if args.len() != 1 {
+        return exec_err!("array_sum needs one argument");
+    }
+
+    match args[0].data_type() {
+        DataType::List(_) => general_array_sum(as_list_array(&args[0])?),
+        DataType::LargeList(_) => general_array_sum(as_large_list_array(&args[0])?),
+        DataType::FixedSizeList(field, _) => {
+            let list_array = compute::cast(&args[0], &DataType::List(field.clone()))?;
+            general_array_sum(as_list_array(&list_array)?)
+        }
+        DataType::Null => Ok(args[0].clone()),
+        data_type => exec_err!("array_sum does not support type '{data_type:?}'"),
+    }
+}
+
+fn general_array_sum<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
+) -> Result<ArrayRef> {
+    // The elements are cast to the result type once, for all rows
+    let sum_type =
+        BuiltinScalarFunction::ArraySum.return_type(&[list_array.data_type().clone()])?;
+    let values = compute::cast(list_array.values(), &sum_type)?;
+    match sum_type {
+        DataType::Int64 => row_sums::<O, Int64Type>(list_array, &values),
+        DataType::UInt64 => row_sums::<O, UInt64Type>(list_array, &values),
+        DataType::Float64 => row_sums::<O, Float64Type>(list_array, &values),
+        DataType::Decimal128(_, _) => row_sums::<O, Decimal128Type>(list_array, &values),
+        DataType::Decimal256(_, _) => row_sums::<O, Decimal256Type>(list_array, &values),
+        data_type => internal_err!("array_sum got unexpected type {data_type:?}"),
+    }
+}
+
+/// Sums the `values` of each row of `list_array`, failing on overflow.
+fn row_sums<O: OffsetSizeTrait, T: ArrowNumericType>(
+    list_array: &GenericListArray<O>,
+    values: &ArrayRef,
+) -> Result<ArrayRef>
+where
+    T::Native: ArrowNativeTypeOp,
+{
+    let values = values.as_primitive::<T>();
+    let sums = list_array
+        .offsets()
+        .windows(2)
+        .enumerate()
+        .map(|(row_index, offset_window)| {
+            if list_array.is_null(row_index) {
+                return Ok(None);
+            }
+            let start = offset_window[0].as_usize();
+            let end = offset_window[1].as_usize();
+            Ok(compute::sum_checked(&values.slice(start, end - start))?)
+        })
+        .collect::<Result<PrimitiveArray<T>>>()?;
+
+    // Keeps the precision and scale of decimals
+    Ok(Arc::new(sums.with_data_type(values.data_type().clone())))
+}
+
+/// Array_max SQL function
+///
+/// Returns the largest non-null element of each row, using the same ordering
+/// as the `MAX` aggregate. A null row, or a row without non-null elements,
+/// gives null.
+///
+/// For example,
+/// ```text
+/// array_max([1, NULL, 3, 2]) => 3
+/// ```
+pub fn array_max(args: &[ArrayRef]) -> Result<ArrayRef> {
+    array_min_max(args, "array_max", max_batch)
+}
+
+/// Array_min SQL function
+///
+/// Returns the smallest non-null element of each row, using the same ordering
+/// as the `MIN` aggregate. A null row, or a row without non-null elements,
+/// gives null.
+///
+/// For example,
+/// ```text
+/// array_min([3, NULL, 1, 2]) => 1
+/// ```
+pub fn array_min(args: &[ArrayRef]) -> Result<ArrayRef> {
+    array_min_max(args, "array_min", min_batch)
+}
+
+fn array_min_max(
+    args: &[ArrayRef],
+    name: &str,
+    op: fn(&ArrayRef) -> Result<ScalarValue>,
+) -> Result<ArrayRef> {
+    if args.len() != 1 {
+        return exec_err!("{name} needs one argument");
+    }
+
+    match args[0].data_type() {
+        DataType::List(_) => general_array_min_max(as_list_array(&args[0])?, name, op),
+        DataType::LargeList(_) => {
+            general_array_min_max(as_large_list_array(&args[0])?, name, op)
+        }
+        DataType::FixedSizeList(field, _) => {
+            let list_array = compute::cast(&args[0], &DataType::List(field.clone()))?;
+            general_array_min_max(as_list_array(&list_array)?, name, op)
+        }
+        DataType::Null => Ok(args[0].clone()),
+        data_type => exec_err!("{name} does not support type '{data_type:?}'"),
+    }
+}
+
+fn general_array_min_max<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
+    name: &str,
+    op: fn(&ArrayRef) -> Result<ScalarValue>,
+) -> Result<ArrayRef> {
+    let values = list_array.values();
+    let data_type = values.data_type();
+    if data_type == &DataType::Null {
+        return Ok(new_null_array(data_type, list_array.len()));
+    }
+    // Dictionaries are compared by their values, the result is encoded again
+    if let DataType::Dictionary(_, value_type) = data_type {
+        let decoded = GenericListArray::<O>::try_new(
+            Arc::new(Field::new("item", value_type.as_ref().clone(), true)),
+            list_array.offsets().clone(),
+            compute::cast(values, value_type)?,
+            list_array.nulls().cloned(),
+        )?;
+        let result = general_array_min_max(&decoded, name, op)?;
+        return Ok(compute::cast(&result, data_type)?);
+    }
+    if data_type.is_nested() {
+        return exec_err!("{name} does not support lists of '{data_type:?}'");
+    }
+    if list_array.is_empty() {
+        return Ok(new_empty_array(data_type));
+    }
+
+    let null_value = ScalarValue::try_from(data_type)?;
+    let scalars = list_array
+        .offsets()
+        .windows(2)
+        .enumerate()
+        .map(|(row_index, offset_window)| {
+            if list_array.is_null(row_index) {
+                return Ok(null_value.clone());
+            }
+            let start = offset_window[0].as_usize();
+            let end = offset_window[1].as_usize();
+            op(&values.slice(start, end - start))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // The scalars carry the timezone of timestamps and the precision and
+    // scale of decimals over to the result
+    ScalarValue::iter_to_array(scalars)
+}
+
 /// Array_dims SQL function
 pub fn array_dims(args: &[ArrayRef]) -> Result<ArrayRef> {
     let list_array = as_list_array(&args[0])?;
@@ -1885,72 +3688,176 @@ fn general_array_has_dispatch<O: OffsetSizeTrait>(
         as_generic_list_array::<O>(array)?
     };
 
-    let mut boolean_builder = BooleanArray::builder(array.len());
-
     let converter = RowConverter::new(vec![SortField::new(array.value_type())])?;
 
-    let element = sub_array.clone();
-    let sub_array = if comparison_type != ComparisonType::Single {
-        as_generic_list_array::<O>(sub_array)?
+    let needle_len = sub_array.len();
+
+    // For `array_has` the needle is a column of elements, which is converted once
+    let (sub_array, element_values) = if comparison_type != ComparisonType::Single {
+        (Some(as_generic_list_array::<O>(sub_array)?), None)
     } else {
-        array
+        (
+            None,
+            Some(converter.convert_columns(std::slice::from_ref(sub_array))?),
+        )
     };
 
-    for (row_idx, (arr, sub_arr)) in array.iter().zip(sub_array.iter()).enumerate() {
+    // A single row haystack or needle is broadcast to the number of rows of the other
+    let haystack_len = array.len();
+    let row_count = haystack_len.max(needle_len);
+    if (haystack_len != 1 && haystack_len != row_count)
+        || (needle_len != 1 && needle_len != row_count)
+    {
+        return exec_err!(
+            "array_has expects arguments with the same number of rows or a single row, got {haystack_len} and {needle_len}"
+        );
+    }
+    let broadcast = |len: usize, i: usize| if len == 1 { 0 } else { i };
+
+    let mut boolean_builder = BooleanArray::builder(row_count);
+
+    for row_idx in 0..row_count {
+        let arr_idx = broadcast(haystack_len, row_idx);
+        let sub_idx = broadcast(needle_len, row_idx);
+        let arr = array.is_valid(arr_idx).then(|| array.value(arr_idx));
+        let sub_arr = match sub_array {
+            Some(sub_array) => sub_array
+                .is_valid(sub_idx)
+                .then(|| sub_array.value(sub_idx)),
+            None => arr.clone(),
+        };
         if let (Some(arr), Some(sub_arr)) = (arr, sub_arr) {
+            let haystack_has_null = arr.null_count() > 0;
             let arr_values = converter.convert_columns(&[arr])?;
-            let sub_arr_values = if comparison_type != ComparisonType::Single {
-                converter.convert_columns(&[sub_arr])?
-            } else {
-                converter.convert_columns(&[element.clone()])?
-            };
-
-            let mut res = match comparison_type {
-                ComparisonType::All => sub_arr_values
-                    .iter()
-                    .dedup()
-                    .all(|elem| arr_values.iter().dedup().any(|x| x == elem)),
-                ComparisonType::Any => sub_arr_values
+            // A null in the needle that is not in the haystack can not be proven
+            // to be contained, making an otherwise true `array_has_all` null
+            let mut unmatched_null = false;
+
+            let res = match comparison_type {
+                ComparisonType::Single => element_values.as_ref().is_some_and(|values| {
+                    arr_values.iter().dedup().any(|x| x == values.row(sub_idx))
+                }),
+                ComparisonType::All => {
+                    let sub_arr = if sub_arr.null_count() > 0 && !haystack_has_null {
+                        unmatched_null = true;
+                        compute::filter(&sub_arr, &compute::is_not_null(&sub_arr)?)?
+                    } else {
+                        sub_arr
+                    };
+                    let sub_arr_values = converter.convert_columns(&[sub_arr])?;
+                    // sorted rows, e.g. from array_sort, can be merged in linear time
+                    if rows_are_sorted(&arr_values) && rows_are_sorted(&sub_arr_values) {
+                        sorted_rows_contain_all(&arr_values, &sub_arr_values)
+                    } else {
+                        rows_contain_all(&arr_values, &sub_arr_values)
+                    }
+                }
+                ComparisonType::Any => converter
+                    .convert_columns(&[sub_arr])?
                     .iter()
                     .dedup()
                     .any(|elem| arr_values.iter().dedup().any(|x| x == elem)),
-                ComparisonType::Single => arr_values
-                    .iter()
-                    .dedup()
-                    .any(|x| x == sub_arr_values.row(row_idx)),
             };
 
-            if comparison_type == ComparisonType::Any {
-                res |= res;
+            if res && unmatched_null {
+                boolean_builder.append_null();
+            } else {
+                boolean_builder.append_value(res);
             }
-
-            boolean_builder.append_value(res);
+        } else {
+            // A null haystack or needle row keeps its place in the output as null
+            boolean_builder.append_null();
         }
     }
     Ok(Arc::new(boolean_builder.finish()))
 }
 
-/// Array_has SQL function
-pub fn array_has(args: &[ArrayRef]) -> Result<ArrayRef> {
-    let array_type = args[0].data_type();
+/// Returns true if every row of `needle` is in `haystack`
+fn rows_contain_all(haystack: &Rows, needle: &Rows) -> bool {
+    needle
+        .iter()
+        .dedup()
+        .all(|elem| haystack.iter().dedup().any(|x| x == elem))
+}
 
-    match array_type {
-        DataType::List(_) => {
-            general_array_has_dispatch::<i32>(&args[0], &args[1], ComparisonType::Single)
-        }
-        DataType::LargeList(_) => {
-            general_array_has_dispatch::<i64>(&args[0], &args[1], ComparisonType::Single)
-        }
-        _ => internal_err!("array_has does not support type '{array_type:?}'."),
-    }
+/// Returns true if every row of `needle` is in `haystack`, both of which must be sorted.
+///
+/// The rows are merged in `O(n + m)` instead of comparing each pair of rows.
+fn sorted_rows_contain_all(haystack: &Rows, needle: &Rows) -> bool {
+    let mut haystack = haystack.iter().peekable();
+    needle.iter().dedup().all(|elem| {
+        while haystack.next_if(|x| *x < elem).is_some() {}
+        haystack.peek().is_some_and(|x| *x == elem)
+    })
 }
 
-/// Array_has_any SQL function
-pub fn array_has_any(args: &[ArrayRef]) -> Result<ArrayRef> {
-    let array_type = args[0].data_type();
+fn rows_are_sorted(rows: &Rows) -> bool {
+    rows.iter().tuple_windows().all(|(a, b)| a <= b)
+}
 
-    match array_type {
-        DataType::List(_) => {
+/// Casts the decimal elements of `array` and a decimal `element` with a different
+/// precision or scale to a common decimal type, so that equal values compare equal.
+///
+/// Other arguments are returned unchanged.
+fn coerce_decimal_element(
+    array: &ArrayRef,
+    element: &ArrayRef,
+) -> Result<(ArrayRef, ArrayRef)> {
+    let (DataType::List(field) | DataType::LargeList(field)) = array.data_type() else {
+        return Ok((array.clone(), element.clone()));
+    };
+    match (field.data_type(), element.data_type()) {
+        (
+            lhs @ (DataType::Decimal128(_, _) | DataType::Decimal256(_, _)),
+            rhs @ (DataType::Decimal128(_, _) | DataType::Decimal256(_, _)),
+        ) if lhs != rhs => {
+            let Some(common_type) = comparison_coercion(lhs, rhs) else {
+                return plan_err!(
+                    "array_has can not compare elements of type {lhs} with {rhs}"
+                );
+            };
+            let field = Arc::new(Field::new(
+                field.name(),
+                common_type.clone(),
+                field.is_nullable(),
+            ));
+            let list_type = match array.data_type() {
+                DataType::LargeList(_) => DataType::LargeList(field),
+                _ => DataType::List(field),
+            };
+            Ok((
+                compute::cast(array, &list_type)?,
+                compute::cast(element, &common_type)?,
+            ))
+        }
+        _ => Ok((array.clone(), element.clone())),
+    }
+}
+
+/// Array_has SQL function
+///
+/// A decimal element is compared by value, regardless of its scale.
+pub fn array_has(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let array_type = args[0].data_type();
+    let (array, element) = coerce_decimal_element(&args[0], &args[1])?;
+
+    match array_type {
+        DataType::List(_) => {
+            general_array_has_dispatch::<i32>(&array, &element, ComparisonType::Single)
+        }
+        DataType::LargeList(_) => {
+            general_array_has_dispatch::<i64>(&array, &element, ComparisonType::Single)
+        }
+        _ => internal_err!("array_has does not support type '{array_type:?}'."),
+    }
+}
+
+/// Array_has_any SQL function
+pub fn array_has_any(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let array_type = args[0].data_type();
+
+    match array_type {
+        DataType::List(_) => {
             general_array_has_dispatch::<i32>(&args[0], &args[1], ComparisonType::Any)
         }
         DataType::LargeList(_) => {
@@ -1961,6 +3868,10 @@ pub fn array_has_any(args: &[ArrayRef]) -> Result<ArrayRef> {
 }
 
 /// Array_has_all SQL function
+///
+/// Follows three-valued logic for null elements of the needle: a null is only
+/// contained in a haystack with a null, otherwise the result is null unless
+/// another element is missing.
 pub fn array_has_all(args: &[ArrayRef]) -> Result<ArrayRef> {
     let array_type = args[0].data_type();
 
@@ -2065,86 +3976,218 @@ pub fn string_to_array<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef
 }
 
 /// array_intersect SQL function
+///
+/// Intersects two or more arrays, keeping the distinct elements of the first array
+/// which are in every other array, in the order of the first array.
+/// Null arrays are ignored, and a null row in any array gives a null row.
 pub fn array_intersect(args: &[ArrayRef]) -> Result<ArrayRef> {
-    assert_eq!(args.len(), 2);
+    if args.len() < 2 {
+        return exec_err!("array_intersect needs at least two arguments");
+    }
 
-    let first_array = &args[0];
-    let second_array = &args[1];
+    let arrays = args
+        .iter()
+        .filter(|array| array.data_type() != &DataType::Null)
+        .collect::<Vec<_>>();
+    match arrays.as_slice() {
+        [] => return Ok(args[0].clone()),
+        [array] => return Ok((*array).clone()),
+        _ => {}
+    }
+    if arrays
+        .iter()
+        .any(|array| matches!(array.data_type(), DataType::FixedSizeList(_, _)))
+    {
+        return not_impl_err!("array_intersect does not support FixedSizeList arguments");
+    }
 
-    match (first_array.data_type(), second_array.data_type()) {
-        (DataType::Null, _) => Ok(second_array.clone()),
-        (_, DataType::Null) => Ok(first_array.clone()),
-        _ => {
-            let first_array = as_list_array(&first_array)?;
-            let second_array = as_list_array(&second_array)?;
+    let types = arrays.iter().map(|a| a.data_type()).collect::<Vec<_>>();
+    if types.iter().all(|dt| matches!(dt, DataType::List(_))) {
+        general_array_intersect::<i32>(&arrays)
+    } else if types.iter().all(|dt| matches!(dt, DataType::LargeList(_))) {
+        general_array_intersect::<i64>(&arrays)
+    } else if types
+        .iter()
+        .all(|dt| matches!(dt, DataType::List(_) | DataType::LargeList(_)))
+    {
+        plan_err!(
+            "array_intersect does not support mixing List and LargeList arguments, got {types:?}"
+        )
+    } else {
+        internal_err!("array_intersect is not implemented for '{types:?}'")
+    }
+}
 
-            if first_array.value_type() != second_array.value_type() {
-                return internal_err!("array_intersect is not implemented for '{first_array:?}' and '{second_array:?}'");
-            }
+/// Intersects lists with the same offset size, see [`array_intersect`].
+fn general_array_intersect<O: OffsetSizeTrait>(arrays: &[&ArrayRef]) -> Result<ArrayRef> {
+    let list_arrays = arrays
+        .iter()
+        .map(|array| as_generic_list_array::<O>(array))
+        .collect::<Result<Vec<_>>>()?;
+    let dt = list_arrays[0].value_type();
+    if list_arrays.iter().any(|array| array.value_type() != dt) {
+        let types = arrays.iter().map(|a| a.data_type()).collect::<Vec<_>>();
+        return internal_err!("array_intersect is not implemented for '{types:?}'");
+    }
+    let row_count = list_arrays[0].len();
+    if list_arrays.iter().any(|array| array.len() != row_count) {
+        return exec_err!("array_intersect expects arrays with the same number of rows");
+    }
 
-            let dt = first_array.value_type();
+    let mut lengths = Vec::with_capacity(row_count);
+    let mut new_arrays = vec![];
+    let mut valid = BooleanBufferBuilder::new(row_count);
 
-            let mut offsets = vec![0];
-            let mut new_arrays = vec![];
+    let converter = RowConverter::new(vec![SortField::new(dt.clone())])?;
+    for row_index in 0..row_count {
+        if list_arrays.iter().any(|array| array.is_null(row_index)) {
+            lengths.push(0);
+            valid.append(false);
+            continue;
+        }
+        let first_values =
+            converter.convert_columns(&[list_arrays[0].value(row_index)])?;
+        // The elements of the first array that are in every array seen so far
+        let mut running_set: HashSet<_> = first_values.iter().collect();
+        for array in &list_arrays[1..] {
+            if running_set.is_empty() {
+                break;
+            }
+            let values = converter.convert_columns(&[array.value(row_index)])?;
+            let values_set: HashSet<_> = values.iter().collect();
+            running_set.retain(|row| values_set.contains(row));
+        }
 
-            let converter = RowConverter::new(vec![SortField::new(dt.clone())])?;
-            for (first_arr, second_arr) in first_array.iter().zip(second_array.iter()) {
-                if let (Some(first_arr), Some(second_arr)) = (first_arr, second_arr) {
-                    let l_values = converter.convert_columns(&[first_arr])?;
-                    let r_values = converter.convert_columns(&[second_arr])?;
+        // Removing each row from the set keeps its first occurrence only
+        let rows = first_values
+            .iter()
+            .filter(|row| running_set.remove(row))
+            .collect::<Vec<_>>();
+        lengths.push(rows.len());
+        let arrays = converter.convert_rows(rows)?;
+        let array = match arrays.first() {
+            Some(array) => array.clone(),
+            None => {
+                return internal_err!("array_intersect: failed to get array from rows")
+            }
+        };
+        new_arrays.push(array);
+        valid.append(true);
+    }
 
-                    let values_set: HashSet<_> = l_values.iter().collect();
-                    let mut rows = Vec::with_capacity(r_values.num_rows());
-                    for r_val in r_values.iter().sorted().dedup() {
-                        if values_set.contains(&r_val) {
-                            rows.push(r_val);
-                        }
-                    }
+    // The elements all come from the first array, so its inner field is kept
+    let field = match list_arrays[0].data_type() {
+        DataType::List(field) | DataType::LargeList(field) => field.clone(),
+        data_type => {
+            return internal_err!("array_intersect got unexpected type {data_type:?}")
+        }
+    };
+    let values = if new_arrays.is_empty() {
+        new_empty_array(&dt)
+    } else {
+        let new_arrays_ref = new_arrays.iter().map(|v| v.as_ref()).collect::<Vec<_>>();
+        compute::concat(&new_arrays_ref)?
+    };
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        field,
+        OffsetBuffer::<O>::from_lengths(lengths),
+        values,
+        Some(NullBuffer::new(valid.finish())),
+    )?))
+}
 
-                    let last_offset: i32 = match offsets.last().copied() {
-                        Some(offset) => offset,
-                        None => return internal_err!("offsets should not be empty"),
-                    };
-                    offsets.push(last_offset + rows.len() as i32);
-                    let arrays = converter.convert_rows(rows)?;
-                    let array = match arrays.first() {
-                        Some(array) => array.clone(),
-                        None => {
-                            return internal_err!(
-                                "array_intersect: failed to get array from rows"
-                            )
-                        }
-                    };
-                    new_arrays.push(array);
-                }
-            }
+/// Whether null elements of the row at `row_index` are kept by `array_distinct`,
+/// a null flag falls back to the default of keeping nulls.
+fn distinct_keeps_nulls(keep_nulls: Option<&BooleanArray>, row_index: usize) -> bool {
+    keep_nulls.map_or(true, |keep_nulls| {
+        keep_nulls.is_null(row_index) || keep_nulls.value(row_index)
+    })
+}
 
-            let field = Arc::new(Field::new("item", dt, true));
-            let offsets = OffsetBuffer::new(offsets.into());
-            let new_arrays_ref =
-                new_arrays.iter().map(|v| v.as_ref()).collect::<Vec<_>>();
-            let values = compute::concat(&new_arrays_ref)?;
-            let arr = Arc::new(ListArray::try_new(field, offsets, values, None)?);
-            Ok(arr)
+/// Removes duplicate elements of each row of a list of strings.
+///
+/// Unlike [`general_array_distinct`], the elements are not converted to rows:
+/// duplicates are found by hashing the strings, and the distinct elements keep
+/// the order in which they first appear in the row.
+fn string_array_distinct<OffsetSize: OffsetSizeTrait, StringOffset: OffsetSizeTrait>(
+    array: &GenericListArray<OffsetSize>,
+    field: &FieldRef,
+    keep_nulls: Option<&BooleanArray>,
+) -> Result<ArrayRef> {
+    let values = as_generic_string_array::<StringOffset>(array.values())?;
+    let mut indices = Vec::with_capacity(values.len());
+    let mut lengths = Vec::with_capacity(array.len());
+    for (row_index, offset_window) in array.offsets().windows(2).enumerate() {
+        if array.is_null(row_index) {
+            lengths.push(0);
+            continue;
         }
+        let keep_nulls = distinct_keeps_nulls(keep_nulls, row_index);
+        let start = offset_window[0].as_usize();
+        let end = offset_window[1].as_usize();
+        let row_start = indices.len();
+        let mut seen = HashSet::with_capacity(end - start);
+        for index in start..end {
+            let value = values.is_valid(index).then(|| values.value(index));
+            if (value.is_some() || keep_nulls) && seen.insert(value) {
+                indices.push(index as u64);
+            }
+        }
+        lengths.push(indices.len() - row_start);
     }
+    let values = compute::take(values, &UInt64Array::from(indices), None)?;
+    Ok(Arc::new(GenericListArray::<OffsetSize>::try_new(
+        field.clone(),
+        OffsetBuffer::from_lengths(lengths),
+        values,
+        array.nulls().cloned(),
+    )?))
 }
 
+/// Removes duplicate elements of each row of `array`.
+///
+/// Null elements compare equal to each other, so by default a single null is
+/// kept. When `keep_nulls` is false for a row, its null elements are dropped.
+///
+/// Rows of strings keep their distinct elements in first-seen order, the
+/// elements of other rows are sorted in ascending order with a kept null
+/// first, as ordered by the default [`SortOptions`] of the row format.
 pub fn general_array_distinct<OffsetSize: OffsetSizeTrait>(
     array: &GenericListArray<OffsetSize>,
     field: &FieldRef,
+    keep_nulls: Option<&BooleanArray>,
 ) -> Result<ArrayRef> {
     let dt = array.value_type();
-    let mut offsets = Vec::with_capacity(array.len());
+    match dt {
+        DataType::Utf8 => {
+            return string_array_distinct::<OffsetSize, i32>(array, field, keep_nulls)
+        }
+        DataType::LargeUtf8 => {
+            return string_array_distinct::<OffsetSize, i64>(array, field, keep_nulls)
+        }
+        _ => {}
+    }
+    let mut offsets = Vec::with_capacity(array.len() + 1);
     offsets.push(OffsetSize::usize_as(0));
     let mut new_arrays = Vec::with_capacity(array.len());
     let converter = RowConverter::new(vec![SortField::new(dt.clone())])?;
     // distinct for each list in ListArray
-    for arr in array.iter().flatten() {
+    for (row_index, arr) in array.iter().enumerate() {
+        let last_offset: OffsetSize = offsets.last().copied().unwrap();
+        let Some(arr) = arr else {
+            // null rows stay null, with an empty slot
+            offsets.push(last_offset);
+            continue;
+        };
+        let keep_nulls = distinct_keeps_nulls(keep_nulls, row_index);
+        let arr = if !keep_nulls && arr.null_count() > 0 {
+            compute::filter(&arr, &compute::is_not_null(&arr)?)?
+        } else {
+            arr
+        };
         let values = converter.convert_columns(&[arr])?;
-        // sort elements in list and remove duplicates
+        // sort elements in list and remove duplicates, nulls sort first
         let rows = values.iter().sorted().dedup().collect::<Vec<_>>();
-        let last_offset: OffsetSize = offsets.last().copied().unwrap();
         offsets.push(last_offset + OffsetSize::usize_as(rows.len()));
         let arrays = converter.convert_rows(rows)?;
         let array = match arrays.get(0) {
@@ -2156,20 +4199,31 @@ pub fn general_array_distinct<OffsetSize: OffsetSizeTrait>(
         new_arrays.push(array);
     }
     let offsets = OffsetBuffer::new(offsets.into());
-    let new_arrays_ref = new_arrays.iter().map(|v| v.as_ref()).collect::<Vec<_>>();
-    let values = compute::concat(&new_arrays_ref)?;
+    let values = if new_arrays.is_empty() {
+        new_empty_array(&dt)
+    } else {
+        let new_arrays_ref = new_arrays.iter().map(|v| v.as_ref()).collect::<Vec<_>>();
+        compute::concat(&new_arrays_ref)?
+    };
     Ok(Arc::new(GenericListArray::<OffsetSize>::try_new(
         field.clone(),
         offsets,
         values,
-        None,
+        array.nulls().cloned(),
     )?))
 }
 
 /// array_distinct SQL function
 /// example: from list [1, 3, 2, 3, 1, 2, 4] to [1, 2, 3, 4]
+///
+/// The optional second argument controls whether a single null element is kept
+/// (the default) or null elements are removed, e.g. from list [1, NULL, 1, NULL]
+/// to [NULL, 1] or [1].
 pub fn array_distinct(args: &[ArrayRef]) -> Result<ArrayRef> {
-    assert_eq!(args.len(), 1);
+    if args.is_empty() || args.len() > 2 {
+        return exec_err!("array_distinct needs one or two arguments");
+    }
+    let keep_nulls = args.get(1).map(|arg| as_boolean_array(arg)).transpose()?;
 
     // handle null
     if args[0].data_type() == &DataType::Null {
@@ -2180,11 +4234,11 @@ pub fn array_distinct(args: &[ArrayRef]) -> Result<ArrayRef> {
     match args[0].data_type() {
         DataType::List(field) => {
             let array = as_list_array(&args[0])?;
-            general_array_distinct(array, field)
+            general_array_distinct(array, field, keep_nulls)
         }
         DataType::LargeList(field) => {
             let array = as_large_list_array(&args[0])?;
-            general_array_distinct(array, field)
+            general_array_distinct(array, field, keep_nulls)
         }
         _ => internal_err!("array_distinct only support list array"),
     }
@@ -2237,6 +4291,812 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_align_array_dimensions_4d() {
+        fn wrap(array: ArrayRef, times: usize) -> ArrayRef {
+            (0..times).fold(array, |array, _| Arc::new(array_into_list_array(array)))
+        }
+
+        // [[1, 2]] and [[[[3]]]]
+        let array1d = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+        ])) as ArrayRef;
+        let array1d_other =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(3)]),
+            ])) as ArrayRef;
+        let array4d = wrap(array1d_other.clone(), 3);
+
+        let res = align_array_dimensions(vec![array1d.clone(), array4d.clone()]).unwrap();
+        assert_eq!(datafusion_common::utils::list_ndims(res[0].data_type()), 4);
+        assert_eq!(&res[0], &wrap(array1d.clone(), 3));
+        assert_eq!(&res[1], &array4d);
+
+        // [[[[1, 2]]], [[[3]]]]
+        let result = array_concat(&[array1d.clone(), array4d]).unwrap();
+        let concatenated = compute::concat(&[
+            wrap(array1d, 2).as_ref(),
+            wrap(array1d_other, 2).as_ref(),
+        ])
+        .unwrap();
+        assert_eq!(&result, &wrap(concatenated, 1));
+    }
+
+    #[test]
+    fn test_array_slice_all_invalid_ranges() {
+        // [1, 2, 3], [4, 5, 6], ... sliced with array_slice(arr, 3, 1)
+        let num_rows = 1024;
+        let values = Int64Array::from_iter_values(0..(num_rows as i64 * 3));
+        let list_array = Arc::new(ListArray::new(
+            Arc::new(Field::new("item", DataType::Int64, true)),
+            OffsetBuffer::from_lengths(vec![3; num_rows]),
+            Arc::new(values),
+            None,
+        )) as ArrayRef;
+        let from_array = Arc::new(Int64Array::from_value(3, num_rows)) as ArrayRef;
+        let to_array = Arc::new(Int64Array::from_value(1, num_rows)) as ArrayRef;
+
+        let result = array_slice(&[list_array, from_array, to_array]).unwrap();
+        let result = as_list_array(&result).unwrap();
+
+        assert_eq!(result.len(), num_rows);
+        assert_eq!(result.null_count(), 0);
+        assert!(result.offsets().iter().all(|offset| *offset == 0));
+        // the values are empty, without holding on to the preallocated capacity
+        assert_eq!(result.values().data_type(), &DataType::Int64);
+        assert_eq!(result.values().len(), 0);
+        assert_eq!(result.values().get_buffer_memory_size(), 0);
+    }
+
+    #[test]
+    fn test_slice_index_to_usize() {
+        assert_eq!(slice_index_to_usize(0).unwrap(), 0);
+        assert_eq!(slice_index_to_usize(42).unwrap(), 42);
+        assert!(slice_index_to_usize(-1).is_err());
+        if usize::BITS < i64::BITS {
+            assert!(slice_index_to_usize(i64::MAX).is_err());
+        } else {
+            assert_eq!(
+                slice_index_to_usize(i64::MAX).unwrap() as u64,
+                i64::MAX as u64
+            );
+        }
+    }
+
+    #[test]
+    fn test_array_slice_and_element_sliced_input() {
+        // [0], [1, 2, 3], NULL, [4, 5], [6, 7, 8, 9], sliced to [1, 2, 3], NULL, [4, 5]
+        let list_array = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(0)]),
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+            Some(vec![Some(4), Some(5)]),
+            Some(vec![Some(6), Some(7), Some(8), Some(9)]),
+        ]);
+        let sliced = Arc::new(list_array.slice(1, 3)) as ArrayRef;
+
+        let from_array = Arc::new(Int64Array::from(vec![2, 1, -1])) as ArrayRef;
+        let to_array = Arc::new(Int64Array::from(vec![3, 2, 2])) as ArrayRef;
+        let result = array_slice(&[sliced.clone(), from_array, to_array]).unwrap();
+        assert_eq!(
+            as_list_array(&result).unwrap(),
+            &ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(2), Some(3)]),
+                Some(vec![]),
+                Some(vec![Some(5)]),
+            ])
+        );
+
+        let indexes = Arc::new(Int64Array::from(vec![1, 1, -1])) as ArrayRef;
+        let result = array_element(&[sliced.clone(), indexes]).unwrap();
+        assert_eq!(
+            as_int64_array(&result).unwrap(),
+            &Int64Array::from(vec![Some(1), None, Some(5)])
+        );
+
+        // slicing twice keeps accumulating the offset
+        let sliced_twice = Arc::new(sliced.slice(2, 1)) as ArrayRef;
+        let indexes = Arc::new(Int64Array::from(vec![2])) as ArrayRef;
+        let result = array_element(&[sliced_twice.clone(), indexes]).unwrap();
+        assert_eq!(as_int64_array(&result).unwrap(), &Int64Array::from(vec![5]));
+        let from_array = Arc::new(Int64Array::from(vec![1])) as ArrayRef;
+        let result = array_slice(&[sliced_twice, from_array]).unwrap();
+        assert_eq!(
+            as_list_array(&result).unwrap(),
+            &ListArray::from_iter_primitive::<Int64Type, _, _>(vec![Some(vec![
+                Some(4),
+                Some(5)
+            ])])
+        );
+    }
+
+    #[test]
+    fn test_array_remove_n_scalar_n() {
+        // [1, 2, 1, 1], [3, 3], [4, 5]
+        let list_array =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), Some(2), Some(1), Some(1)]),
+                Some(vec![Some(3), Some(3)]),
+                Some(vec![Some(4), Some(5)]),
+            ])) as ArrayRef;
+        let element_array = Arc::new(Int64Array::from(vec![1, 3, 6])) as ArrayRef;
+        let n = Arc::new(Int64Array::from(vec![1])) as ArrayRef;
+
+        let result =
+            array_remove_n(&[list_array.clone(), element_array.clone(), n]).unwrap();
+        assert_eq!(
+            as_list_array(&result).unwrap(),
+            &ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(2), Some(1), Some(1)]),
+                Some(vec![Some(3)]),
+                Some(vec![Some(4), Some(5)]),
+            ])
+        );
+
+        let n = Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef;
+        let err = array_remove_n(&[list_array, element_array, n]).unwrap_err();
+        assert!(err.to_string().contains(
+            "array_remove_n expects n with the same number of rows as the array (3) or a single row, got 2"
+        ));
+    }
+
+    #[test]
+    fn test_array_replace_n_scalar_n() {
+        // [1, 2, 1, 1], [3, 3, 3], [4, 5]
+        let list_array =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), Some(2), Some(1), Some(1)]),
+                Some(vec![Some(3), Some(3), Some(3)]),
+                Some(vec![Some(4), Some(5)]),
+            ])) as ArrayRef;
+        let from_array = Arc::new(Int64Array::from(vec![1, 3, 6])) as ArrayRef;
+        let to_array = Arc::new(Int64Array::from(vec![0, 0, 0])) as ArrayRef;
+        let n = Arc::new(Int64Array::from(vec![2])) as ArrayRef;
+
+        let result = array_replace_n(&[
+            list_array.clone(),
+            from_array.clone(),
+            to_array.clone(),
+            n,
+        ])
+        .unwrap();
+        assert_eq!(
+            as_list_array(&result).unwrap(),
+            &ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(0), Some(2), Some(0), Some(1)]),
+                Some(vec![Some(0), Some(0), Some(3)]),
+                Some(vec![Some(4), Some(5)]),
+            ])
+        );
+
+        let n = Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef;
+        let err = array_replace_n(&[list_array, from_array, to_array, n]).unwrap_err();
+        assert!(err.to_string().contains(
+            "array_replace_n expects n with the same number of rows as the array (3) or a single row, got 2"
+        ));
+    }
+
+    #[test]
+    fn test_array_to_string_null_string_rows() {
+        // [1, NULL], [NULL, 2], [3]
+        let list_array =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), None]),
+                Some(vec![None, Some(2)]),
+                Some(vec![Some(3)]),
+            ])) as ArrayRef;
+        let delimiter = Arc::new(StringArray::from(vec![","; 3])) as ArrayRef;
+
+        // a single row null string is used for every row
+        let null_string = Arc::new(StringArray::from(vec!["*"])) as ArrayRef;
+        let result =
+            array_to_string(&[list_array.clone(), delimiter.clone(), null_string])
+                .unwrap();
+        assert_eq!(
+            as_string_array(&result).unwrap(),
+            &StringArray::from(vec!["1,*", "*,2", "3"])
+        );
+
+        let null_string = Arc::new(StringArray::from(vec!["*", "-"])) as ArrayRef;
+        let err = array_to_string(&[list_array, delimiter, null_string]).unwrap_err();
+        assert!(err.to_string().contains(
+            "array_to_string expects a null string with the same number of rows as the array (3) or a single row, got 2"
+        ));
+    }
+
+    #[test]
+    fn test_array_concat_null_row_with_values() {
+        // A null row with values behind it, [9] masked as NULL, concatenated with []
+        let masked = Arc::new(ListArray::new(
+            Arc::new(Field::new("item", DataType::Int64, true)),
+            OffsetBuffer::from_lengths(vec![1]),
+            Arc::new(Int64Array::from(vec![9])),
+            Some(NullBuffer::new_null(1)),
+        )) as ArrayRef;
+        let empty = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![]),
+        ])) as ArrayRef;
+
+        let result = array_concat(&[masked, empty]).unwrap();
+        assert_eq!(
+            as_list_array(&result).unwrap(),
+            &ListArray::from_iter_primitive::<Int64Type, _, _>(vec![Some(vec![])])
+        );
+    }
+
+    #[test]
+    fn test_array_remove_sliced_input() {
+        // [1], [1, 2, 1], NULL, [2, 2], [1, 3], sliced to [1, 2, 1], NULL, [2, 2]
+        let list_array = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            Some(vec![Some(1), Some(2), Some(1)]),
+            None,
+            Some(vec![Some(2), Some(2)]),
+            Some(vec![Some(1), Some(3)]),
+        ]);
+        let sliced = Arc::new(list_array.slice(1, 3)) as ArrayRef;
+        let element = Arc::new(Int64Array::from(vec![1, 1, 2])) as ArrayRef;
+
+        let result = array_remove(&[sliced.clone(), element.clone()]).unwrap();
+        assert_eq!(
+            as_list_array(&result).unwrap(),
+            &ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(2), Some(1)]),
+                None,
+                Some(vec![Some(2)]),
+            ])
+        );
+
+        let result = array_remove_all(&[sliced, element]).unwrap();
+        assert_eq!(
+            as_list_array(&result).unwrap(),
+            &ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(2)]),
+                None,
+                Some(vec![]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_array_concat_incompatible_element_types() {
+        let int_array = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+        ])) as ArrayRef;
+        let string_array =
+            Arc::new(array_into_list_array(Arc::new(StringArray::from(vec![
+                "a",
+            ])))) as ArrayRef;
+        let null_array = make_array(&[Arc::new(NullArray::new(1))]).unwrap();
+
+        let result = array_concat(&[int_array, null_array, string_array]);
+
+        assert_eq!(
+            result.unwrap_err().strip_backtrace(),
+            "Error during planning: array_concat received incompatible element types: '[Int64, Null, Utf8]', mismatched at positions [3]."
+        );
+    }
+
+    #[test]
+    fn test_array_union_inner_field() {
+        let values = Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef;
+        let offsets = OffsetBuffer::from_lengths(vec![3]);
+        let left = Arc::new(ListArray::new(
+            Arc::new(Field::new("item", DataType::Int64, true)),
+            offsets.clone(),
+            values.clone(),
+            None,
+        )) as ArrayRef;
+        let right = Arc::new(ListArray::new(
+            Arc::new(Field::new("element", DataType::Int64, true)),
+            offsets.clone(),
+            values,
+            None,
+        )) as ArrayRef;
+
+        // differing inner field names, the left field is used
+        let result = array_union(&[left.clone(), right.clone()]).unwrap();
+        assert_eq!(result.data_type(), left.data_type());
+        let result = array_union(&[right.clone(), left.clone()]).unwrap();
+        assert_eq!(result.data_type(), right.data_type());
+
+        // differing inner data types is an error
+        let strings = Arc::new(ListArray::new(
+            Arc::new(Field::new("item", DataType::Utf8, true)),
+            offsets,
+            Arc::new(StringArray::from(vec!["a", "b", "c"])),
+            None,
+        )) as ArrayRef;
+        assert_eq!(
+            array_union(&[left, strings]).unwrap_err().strip_backtrace(),
+            "Error during planning: array_union received incompatible types: '[List(Field { name: \"item\", data_type: Int64, nullable: true, dict_id: 0, dict_is_ordered: false, metadata: {} }), List(Field { name: \"item\", data_type: Utf8, nullable: true, dict_id: 0, dict_is_ordered: false, metadata: {} })]'."
+        );
+    }
+
+    #[test]
+    fn test_array_distinct_inner_field() {
+        let metadata = std::collections::HashMap::from([(
+            String::from("key"),
+            String::from("value"),
+        )]);
+        let field = Arc::new(
+            Field::new("element", DataType::Int64, true).with_metadata(metadata.clone()),
+        );
+        let values = Arc::new(Int64Array::from(vec![1, 1, 2, 3, 3])) as ArrayRef;
+
+        let list = Arc::new(ListArray::new(
+            field.clone(),
+            OffsetBuffer::from_lengths(vec![3, 2]),
+            values.clone(),
+            None,
+        )) as ArrayRef;
+        let result = array_distinct(std::slice::from_ref(&list)).unwrap();
+        assert_eq!(result.data_type(), list.data_type());
+
+        let large_list = Arc::new(LargeListArray::new(
+            field.clone(),
+            OffsetBuffer::from_lengths(vec![3, 2]),
+            values,
+            None,
+        )) as ArrayRef;
+        let result = array_distinct(std::slice::from_ref(&large_list)).unwrap();
+        assert_eq!(result.data_type(), large_list.data_type());
+
+        // the inner field of nested lists is kept as well
+        let nested = Arc::new(ListArray::new(
+            Arc::new(
+                Field::new("inner", list.data_type().clone(), true)
+                    .with_metadata(metadata),
+            ),
+            OffsetBuffer::from_lengths(vec![2]),
+            list,
+            None,
+        )) as ArrayRef;
+        let result = array_distinct(std::slice::from_ref(&nested)).unwrap();
+        assert_eq!(result.data_type(), nested.data_type());
+    }
+
+    #[test]
+    fn test_array_except_null_rows() {
+        // [1, 2], NULL (backed by [3])
+        let left = Arc::new(ListArray::new(
+            Arc::new(Field::new("item", DataType::Int64, true)),
+            OffsetBuffer::from_lengths(vec![2, 1]),
+            Arc::new(Int64Array::from(vec![1, 2, 3])),
+            Some(NullBuffer::from(vec![true, false])),
+        )) as ArrayRef;
+        // NULL (backed by [1]), [3]
+        let right = Arc::new(ListArray::new(
+            Arc::new(Field::new("item", DataType::Int64, true)),
+            OffsetBuffer::from_lengths(vec![1, 1]),
+            Arc::new(Int64Array::from(vec![1, 3])),
+            Some(NullBuffer::from(vec![false, true])),
+        )) as ArrayRef;
+
+        let result = array_except(&[left, right]).unwrap();
+        let result = as_list_array(&result).unwrap();
+
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+        ]);
+        assert_eq!(result, &expected);
+        assert_eq!(result.offsets().as_ref(), &[0, 2, 2]);
+    }
+
+    #[test]
+    fn test_array_slice_mismatched_lengths() {
+        let list_array =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), Some(2), Some(3)]),
+                Some(vec![Some(4), Some(5)]),
+            ])) as ArrayRef;
+        let from_array = Arc::new(Int64Array::from(vec![1, 1, 1])) as ArrayRef;
+        let to_array = Arc::new(Int64Array::from(vec![2, 2])) as ArrayRef;
+
+        let result = array_slice(&[list_array, from_array, to_array]);
+
+        assert_eq!(
+            result.unwrap_err().strip_backtrace(),
+            "Execution error: array_slice expects from and to indexes with the same number of rows as the array (2), got 3 and 2"
+        );
+    }
+
+    #[test]
+    fn test_make_array_dictionary_other_key_type() {
+        let dict1 = Arc::new(
+            vec![Some("a"), Some("b"), None]
+                .into_iter()
+                .collect::<DictionaryArray<arrow::datatypes::Int32Type>>(),
+        ) as ArrayRef;
+        let dict2 = Arc::new(
+            vec![Some("c"), Some("a"), Some("c")]
+                .into_iter()
+                .collect::<DictionaryArray<arrow::datatypes::Int8Type>>(),
+        ) as ArrayRef;
+        let list_type = |value_type: &DataType| {
+            DataType::List(Arc::new(Field::new("item", value_type.clone(), true)))
+        };
+
+        // a dictionary with another key type is re-encoded like the first argument
+        let result = make_array(&[dict1.clone(), dict2]).unwrap();
+        assert_eq!(result.data_type(), &list_type(dict1.data_type()));
+        let decoded = compute::cast(&result, &list_type(&DataType::Utf8)).unwrap();
+        assert_eq!(
+            as_list_array(&decoded).unwrap(),
+            &ListArray::new(
+                Arc::new(Field::new("item", DataType::Utf8, true)),
+                OffsetBuffer::from_lengths(vec![2, 2, 2]),
+                Arc::new(StringArray::from(vec![
+                    Some("a"),
+                    Some("c"),
+                    Some("b"),
+                    Some("a"),
+                    None,
+                    Some("c"),
+                ])),
+                None,
+            )
+        );
+    }
+
+    #[test]
+    fn test_make_array_dictionary_key_overflow() {
+        // 100 distinct values each, 200 merged values do not fit Int8 keys
+        let dictionary = |prefix: &str| {
+            Arc::new(
+                (0..100)
+                    .map(|i| format!("{prefix}{i}"))
+                    .collect::<Vec<_>>()
+                    .iter()
+                    .map(String::as_str)
+                    .collect::<DictionaryArray<arrow::datatypes::Int8Type>>(),
+            ) as ArrayRef
+        };
+        let result = make_array(&[dictionary("a"), dictionary("b")]).unwrap();
+        let result = as_list_array(&result).unwrap();
+        assert_eq!(result.value_type(), DataType::Utf8);
+        assert_eq!(result.len(), 100);
+        assert_eq!(
+            as_string_array(&result.value(99)).unwrap(),
+            &StringArray::from(vec!["a99", "b99"])
+        );
+
+        // 100 shared values still fit
+        let result = make_array(&[dictionary("a"), dictionary("a")]).unwrap();
+        assert_eq!(
+            as_list_array(&result).unwrap().value_type(),
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8))
+        );
+    }
+
+    #[test]
+    fn test_array_concat_broadcast_single_row() {
+        // [1, 2]
+        let scalar = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+        ])) as ArrayRef;
+        // [3], NULL, [4, 5]
+        let column = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(3)]),
+            None,
+            Some(vec![Some(4), Some(5)]),
+        ])) as ArrayRef;
+
+        let result = array_concat(&[scalar.clone(), column.clone()]).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2), Some(3)]),
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1), Some(2), Some(4), Some(5)]),
+        ]);
+        assert_eq!(as_list_array(&result).unwrap(), &expected);
+
+        let result = array_concat(&[column.clone(), scalar]).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(3), Some(1), Some(2)]),
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(4), Some(5), Some(1), Some(2)]),
+        ]);
+        assert_eq!(as_list_array(&result).unwrap(), &expected);
+
+        // two rows cannot be broadcast to three
+        let two_rows = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            Some(vec![Some(2)]),
+        ])) as ArrayRef;
+        let err = array_concat(&[two_rows, column]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("array_concat expects arguments with the same number of rows (3) or a single row, got 2"));
+    }
+
+    #[test]
+    fn test_array_has_broadcast_single_row() {
+        // [1, 2], [3], [2, 4]
+        let haystack = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(3)]),
+            Some(vec![Some(2), Some(4)]),
+        ])) as ArrayRef;
+        let needle = Arc::new(Int64Array::from(vec![2])) as ArrayRef;
+
+        let result = array_has(&[haystack, needle]).unwrap();
+        assert_eq!(
+            as_boolean_array(&result).unwrap(),
+            &BooleanArray::from(vec![true, false, true])
+        );
+
+        // [1, 2]
+        let haystack = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+        ])) as ArrayRef;
+        let needle = Arc::new(Int64Array::from(vec![2, 3, 1])) as ArrayRef;
+
+        let result = array_has(&[haystack, needle]).unwrap();
+        assert_eq!(
+            as_boolean_array(&result).unwrap(),
+            &BooleanArray::from(vec![true, false, true])
+        );
+    }
+
+    #[test]
+    fn test_array_append_and_prepend_broadcast_element() {
+        // [1, 2], [], [3]
+        let list_array =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![]),
+                Some(vec![Some(3)]),
+            ])) as ArrayRef;
+        let element = Arc::new(Int64Array::from(vec![9])) as ArrayRef;
+
+        let result = array_append(&[list_array.clone(), element.clone()]).unwrap();
+        assert_eq!(
+            as_list_array(&result).unwrap(),
+            &ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), Some(2), Some(9)]),
+                Some(vec![Some(9)]),
+                Some(vec![Some(3), Some(9)]),
+            ])
+        );
+
+        let result = array_prepend(&[element, list_array.clone()]).unwrap();
+        assert_eq!(
+            as_list_array(&result).unwrap(),
+            &ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(9), Some(1), Some(2)]),
+                Some(vec![Some(9)]),
+                Some(vec![Some(9), Some(3)]),
+            ])
+        );
+
+        let elements = Arc::new(Int64Array::from(vec![7, 8])) as ArrayRef;
+        assert!(array_append(&[list_array, elements]).is_err());
+    }
+
+    #[test]
+    fn test_sorted_rows_contain_all() {
+        let converter = RowConverter::new(vec![SortField::new(DataType::Int64)]).unwrap();
+        let rows = |values: Vec<Option<i64>>| {
+            converter
+                .convert_columns(&[Arc::new(Int64Array::from(values)) as ArrayRef])
+                .unwrap()
+        };
+
+        let haystack = rows(vec![None, Some(1), Some(2), Some(2), Some(5), Some(8)]);
+        for needle in [
+            vec![],
+            vec![Some(1)],
+            vec![Some(2), Some(2), Some(5)],
+            vec![None, Some(1), Some(8)],
+            vec![Some(0)],
+            vec![Some(2), Some(3)],
+            vec![Some(8), Some(9)],
+            vec![None, Some(1), Some(2), Some(5), Some(8), Some(10)],
+        ] {
+            let needle = rows(needle);
+            assert!(rows_are_sorted(&needle));
+            assert_eq!(
+                sorted_rows_contain_all(&haystack, &needle),
+                rows_contain_all(&haystack, &needle)
+            );
+        }
+        assert!(rows_are_sorted(&haystack));
+        assert!(!rows_are_sorted(&rows(vec![Some(2), Some(1)])));
+
+        // the same result when the rows are not sorted
+        let haystack = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2), Some(5)]),
+            Some(vec![Some(5), Some(2), Some(1)]),
+            Some(vec![Some(1), Some(2), Some(5)]),
+        ])) as ArrayRef;
+        let needle = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(2), Some(5)]),
+            Some(vec![Some(2), Some(5)]),
+            Some(vec![Some(2), Some(3)]),
+        ])) as ArrayRef;
+        let result = array_has_all(&[haystack, needle]).unwrap();
+        assert_eq!(
+            as_boolean_array(&result).unwrap(),
+            &BooleanArray::from(vec![true, true, false])
+        );
+    }
+
+    #[test]
+    fn test_array_element_constant_index() {
+        // 1000 rows of 0 to 4 elements, every seventh row null
+        let list_array =
+            ListArray::from_iter_primitive::<Int64Type, _, _>((0..1000_i64).map(|row| {
+                (row % 7 != 0)
+                    .then(|| (0..row % 5).map(|i| Some(row * 10 + i)).collect::<Vec<_>>())
+            }));
+        // The rows with 4 elements only
+        let full_rows =
+            ListArray::from_iter_primitive::<Int64Type, _, _>((0..1000_i64).map(|row| {
+                (row % 7 != 0)
+                    .then(|| (0..4).map(|i| Some(row * 10 + i)).collect::<Vec<_>>())
+            }));
+
+        for list_array in [&list_array, &full_rows] {
+            for index in [1, 2, 4, 5, -1, 0] {
+                let indexes = Int64Array::from(vec![index; list_array.len()]);
+                let fast = array_element_by_index(list_array, &indexes, None).unwrap();
+                let general = general_array_element(list_array, &indexes, None).unwrap();
+                assert_eq!(&fast, &general, "index {index}");
+            }
+        }
+
+        // Only a constant positive index in bounds for every row takes the fast path
+        let constant_index = |list_array: &ListArray, index: i64| {
+            let indexes = Int64Array::from(vec![index; list_array.len()]);
+            array_element_constant_index(list_array, &indexes).unwrap()
+        };
+        assert!(constant_index(&full_rows, 4).is_some());
+        assert!(constant_index(&full_rows, 5).is_none());
+        assert!(constant_index(&full_rows, -1).is_none());
+        assert!(constant_index(&list_array, 1).is_none());
+        let mixed_indexes = Int64Array::from_iter_values((0..1000).map(|i| i % 2 + 1));
+        assert!(array_element_constant_index(&full_rows, &mixed_indexes)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_array_offsets_from_lengths() {
+        let list_array =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), Some(2), Some(1)]),
+                None,
+                Some(vec![]),
+                Some(vec![Some(1)]),
+            ])) as ArrayRef;
+        let element = Arc::new(Int64Array::from(vec![1])) as ArrayRef;
+        let elements = Arc::new(Int64Array::from(vec![1, 1, 1, 1])) as ArrayRef;
+        let to = Arc::new(Int64Array::from(vec![9, 9, 9, 9])) as ArrayRef;
+
+        let result = array_append(&[list_array.clone(), element.clone()]).unwrap();
+        let result = as_list_array(&result).unwrap();
+        assert_eq!(result.offsets().as_ref(), &[0, 4, 5, 6, 8]);
+        assert_eq!(result.values().len(), 8);
+
+        let result = array_prepend(&[element, list_array.clone()]).unwrap();
+        let result = as_list_array(&result).unwrap();
+        assert_eq!(result.offsets().as_ref(), &[0, 4, 5, 6, 8]);
+        assert_eq!(result.values().len(), 8);
+
+        let result =
+            array_replace_all(&[list_array.clone(), elements.clone(), to]).unwrap();
+        let result = as_list_array(&result).unwrap();
+        assert_eq!(result.offsets().as_ref(), &[0, 3, 3, 3, 4]);
+        assert_eq!(result.values().len(), 4);
+        assert!(result.is_null(1));
+
+        let result = array_remove_all(&[list_array, elements]).unwrap();
+        let result = as_list_array(&result).unwrap();
+        assert_eq!(result.offsets().as_ref(), &[0, 1, 1, 1, 1]);
+        assert_eq!(result.values().len(), 1);
+        assert!(result.is_null(1));
+    }
+
+    #[test]
+    fn test_array_reverse_sliced() {
+        // [1, 2], [3, 4, 5], NULL, [6], []
+        let list_array = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(3), Some(4), Some(5)]),
+            None,
+            Some(vec![Some(6), None]),
+            Some(vec![]),
+        ]);
+        // [3, 4, 5], NULL, [6, NULL]
+        let sliced = Arc::new(list_array.slice(1, 3)) as ArrayRef;
+
+        let result = array_reverse(&[sliced]).unwrap();
+        assert_eq!(
+            as_list_array(&result).unwrap(),
+            &ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(5), Some(4), Some(3)]),
+                None,
+                Some(vec![None, Some(6)]),
+            ])
+        );
+
+        let large_list_array =
+            LargeListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![Some(3), Some(4)]),
+            ]);
+        let result = array_reverse(&[Arc::new(large_list_array.slice(1, 1))]).unwrap();
+        assert_eq!(
+            as_large_list_array(&result).unwrap(),
+            &LargeListArray::from_iter_primitive::<Int64Type, _, _>(vec![Some(vec![
+                Some(4),
+                Some(3)
+            ])])
+        );
+    }
+
+    #[test]
+    fn test_array_intersect_keeps_field_and_null_rows() {
+        let field = Arc::new(
+            Field::new("element", DataType::Int64, true).with_metadata(
+                [("key".to_string(), "value".to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+        );
+        // [1, 2, 3], NULL, [4]
+        let left = Arc::new(ListArray::new(
+            field.clone(),
+            OffsetBuffer::from_lengths(vec![3, 0, 1]),
+            Arc::new(Int64Array::from(vec![1, 2, 3, 4])),
+            Some(NullBuffer::from(vec![true, false, true])),
+        )) as ArrayRef;
+        // [3, 1], [5], NULL
+        let right = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(3), Some(1)]),
+            Some(vec![Some(5)]),
+            None,
+        ])) as ArrayRef;
+
+        let result = array_intersect(&[left, right]).unwrap();
+        assert_eq!(result.data_type(), &DataType::List(field));
+        let result = as_list_array(&result).unwrap();
+        assert_eq!(result.offsets().as_ref(), &[0, 2, 2, 2]);
+        assert_eq!(
+            result.values().as_ref(),
+            &Int64Array::from(vec![1, 3]) as &dyn Array
+        );
+        assert!(result.is_valid(0));
+        assert!(result.is_null(1));
+        assert!(result.is_null(2));
+    }
+
+    #[test]
+    fn test_array_sort_sliced_input() {
+        // [9], NULL, [3, 1], NULL, [2, NULL], [8], sliced to NULL, [3, 1], NULL, [2, NULL]
+        let list_array = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(9)]),
+            None,
+            Some(vec![Some(3), Some(1)]),
+            None,
+            Some(vec![Some(2), None]),
+            Some(vec![Some(8)]),
+        ]);
+        let list_array = Arc::new(list_array.slice(1, 4)) as ArrayRef;
+
+        let result = array_sort(&[list_array]).unwrap();
+        assert_eq!(
+            as_list_array(&result).unwrap(),
+            &ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                None,
+                Some(vec![Some(1), Some(3)]),
+                None,
+                Some(vec![None, Some(2)]),
+            ])
+        );
+    }
+
     #[test]
     fn test_check_invalid_datatypes() {
         let data = vec![Some(vec![Some(1), Some(2), Some(3)])];
@@ -332,9 +332,18 @@ pub fn create_physical_fun(
         BuiltinScalarFunction::ArraySort => {
             Arc::new(|args| make_scalar_function(array_expressions::array_sort)(args))
         }
+        BuiltinScalarFunction::ArrayCompact => {
+            Arc::new(|args| make_scalar_function(array_expressions::array_compact)(args))
+        }
         BuiltinScalarFunction::ArrayConcat => {
             Arc::new(|args| make_scalar_function(array_expressions::array_concat)(args))
         }
+        BuiltinScalarFunction::ArrayConcatDistinct => Arc::new(|args| {
+            make_scalar_function(array_expressions::array_concat_distinct)(args)
+        }),
+        BuiltinScalarFunction::ArrayConcatFlat => Arc::new(|args| {
+            make_scalar_function(array_expressions::array_concat_flat)(args)
+        }),
         BuiltinScalarFunction::ArrayEmpty => {
             Arc::new(|args| make_scalar_function(array_expressions::array_empty)(args))
         }
@@ -350,6 +359,9 @@ pub fn create_physical_fun(
         BuiltinScalarFunction::ArrayDims => {
             Arc::new(|args| make_scalar_function(array_expressions::array_dims)(args))
         }
+        BuiltinScalarFunction::ArrayDistance => {
+            Arc::new(|args| make_scalar_function(array_expressions::array_distance)(args))
+        }
         BuiltinScalarFunction::ArrayDistinct => {
             Arc::new(|args| make_scalar_function(array_expressions::array_distinct)(args))
         }
@@ -365,6 +377,12 @@ pub fn create_physical_fun(
         BuiltinScalarFunction::Flatten => {
             Arc::new(|args| make_scalar_function(array_expressions::flatten)(args))
         }
+        BuiltinScalarFunction::ArrayMax => {
+            Arc::new(|args| make_scalar_function(array_expressions::array_max)(args))
+        }
+        BuiltinScalarFunction::ArrayMin => {
+            Arc::new(|args| make_scalar_function(array_expressions::array_min)(args))
+        }
         BuiltinScalarFunction::ArrayNdims => {
             Arc::new(|args| make_scalar_function(array_expressions::array_ndims)(args))
         }
@@ -377,6 +395,9 @@ pub fn create_physical_fun(
         BuiltinScalarFunction::ArrayPosition => {
             Arc::new(|args| make_scalar_function(array_expressions::array_position)(args))
         }
+        BuiltinScalarFunction::ArrayPositionFromEnd => Arc::new(|args| {
+            make_scalar_function(array_expressions::array_position_from_end)(args)
+        }),
         BuiltinScalarFunction::ArrayPositions => Arc::new(|args| {
             make_scalar_function(array_expressions::array_positions)(args)
         }),
@@ -404,9 +425,18 @@ pub fn create_physical_fun(
         BuiltinScalarFunction::ArrayReplaceAll => Arc::new(|args| {
             make_scalar_function(array_expressions::array_replace_all)(args)
         }),
+        BuiltinScalarFunction::ArrayReverse => {
+            Arc::new(|args| make_scalar_function(array_expressions::array_reverse)(args))
+        }
         BuiltinScalarFunction::ArraySlice => {
             Arc::new(|args| make_scalar_function(array_expressions::array_slice)(args))
         }
+        BuiltinScalarFunction::ArraySublist => {
+            Arc::new(|args| make_scalar_function(array_expressions::array_sublist)(args))
+        }
+        BuiltinScalarFunction::ArraySum => {
+            Arc::new(|args| make_scalar_function(array_expressions::array_sum)(args))
+        }
         BuiltinScalarFunction::ArrayToString => Arc::new(|args| {
             make_scalar_function(array_expressions::array_to_string)(args)
         }),
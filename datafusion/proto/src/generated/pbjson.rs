@@ -21137,6 +21137,16 @@ impl serde::Serialize for ScalarFunction {
             Self::FindInSet => "FindInSet",
             Self::ArraySort => "ArraySort",
             Self::ArrayDistinct => "ArrayDistinct",
+            Self::ArraySublist => "ArraySublist",
+            Self::ArrayConcatDistinct => "ArrayConcatDistinct",
+            Self::ArrayPositionFromEnd => "ArrayPositionFromEnd",
+            Self::ArrayReverse => "ArrayReverse",
+            Self::ArrayDistance => "ArrayDistance",
+            Self::ArrayConcatFlat => "ArrayConcatFlat",
+            Self::ArrayCompact => "ArrayCompact",
+            Self::ArraySum => "ArraySum",
+            Self::ArrayMax => "ArrayMax",
+            Self::ArrayMin => "ArrayMin",
         };
         serializer.serialize_str(variant)
     }
@@ -21278,6 +21288,16 @@ impl<'de> serde::Deserialize<'de> for ScalarFunction {
             "FindInSet",
             "ArraySort",
             "ArrayDistinct",
+            "ArraySublist",
+            "ArrayConcatDistinct",
+            "ArrayPositionFromEnd",
+            "ArrayReverse",
+            "ArrayDistance",
+            "ArrayConcatFlat",
+            "ArrayCompact",
+            "ArraySum",
+            "ArrayMax",
+            "ArrayMin",
         ];
 
         struct GeneratedVisitor;
@@ -21448,6 +21468,16 @@ impl<'de> serde::Deserialize<'de> for ScalarFunction {
                     "FindInSet" => Ok(ScalarFunction::FindInSet),
                     "ArraySort" => Ok(ScalarFunction::ArraySort),
                     "ArrayDistinct" => Ok(ScalarFunction::ArrayDistinct),
+                    "ArraySublist" => Ok(ScalarFunction::ArraySublist),
+                    "ArrayConcatDistinct" => Ok(ScalarFunction::ArrayConcatDistinct),
+                    "ArrayPositionFromEnd" => Ok(ScalarFunction::ArrayPositionFromEnd),
+                    "ArrayReverse" => Ok(ScalarFunction::ArrayReverse),
+                    "ArrayDistance" => Ok(ScalarFunction::ArrayDistance),
+                    "ArrayConcatFlat" => Ok(ScalarFunction::ArrayConcatFlat),
+                    "ArrayCompact" => Ok(ScalarFunction::ArrayCompact),
+                    "ArraySum" => Ok(ScalarFunction::ArraySum),
+                    "ArrayMax" => Ok(ScalarFunction::ArrayMax),
+                    "ArrayMin" => Ok(ScalarFunction::ArrayMin),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -2621,6 +2621,16 @@ pub enum ScalarFunction {
     FindInSet = 127,
     ArraySort = 128,
     ArrayDistinct = 129,
+    ArraySublist = 130,
+    ArrayConcatDistinct = 131,
+    ArrayPositionFromEnd = 132,
+    ArrayReverse = 133,
+    ArrayDistance = 134,
+    ArrayConcatFlat = 135,
+    ArrayCompact = 136,
+    ArraySum = 137,
+    ArrayMax = 138,
+    ArrayMin = 139,
 }
 impl ScalarFunction {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -2759,6 +2769,16 @@ impl ScalarFunction {
             ScalarFunction::FindInSet => "FindInSet",
             ScalarFunction::ArraySort => "ArraySort",
             ScalarFunction::ArrayDistinct => "ArrayDistinct",
+            ScalarFunction::ArraySublist => "ArraySublist",
+            ScalarFunction::ArrayConcatDistinct => "ArrayConcatDistinct",
+            ScalarFunction::ArrayPositionFromEnd => "ArrayPositionFromEnd",
+            ScalarFunction::ArrayReverse => "ArrayReverse",
+            ScalarFunction::ArrayDistance => "ArrayDistance",
+            ScalarFunction::ArrayConcatFlat => "ArrayConcatFlat",
+            ScalarFunction::ArrayCompact => "ArrayCompact",
+            ScalarFunction::ArraySum => "ArraySum",
+            ScalarFunction::ArrayMax => "ArrayMax",
+            ScalarFunction::ArrayMin => "ArrayMin",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -2894,6 +2914,16 @@ impl ScalarFunction {
             "FindInSet" => Some(Self::FindInSet),
             "ArraySort" => Some(Self::ArraySort),
             "ArrayDistinct" => Some(Self::ArrayDistinct),
+            "ArraySublist" => Some(Self::ArraySublist),
+            "ArrayConcatDistinct" => Some(Self::ArrayConcatDistinct),
+            "ArrayPositionFromEnd" => Some(Self::ArrayPositionFromEnd),
+            "ArrayReverse" => Some(Self::ArrayReverse),
+            "ArrayDistance" => Some(Self::ArrayDistance),
+            "ArrayConcatFlat" => Some(Self::ArrayConcatFlat),
+            "ArrayCompact" => Some(Self::ArrayCompact),
+            "ArraySum" => Some(Self::ArraySum),
+            "ArrayMax" => Some(Self::ArrayMax),
+            "ArrayMin" => Some(Self::ArrayMin),
             _ => None,
         }
     }
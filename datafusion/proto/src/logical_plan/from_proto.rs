@@ -41,11 +41,13 @@ use datafusion_common::{
 };
 use datafusion_expr::window_frame::{check_window_frame, regularize_window_order_by};
 use datafusion_expr::{
-    abs, acos, acosh, array, array_append, array_concat, array_dims, array_distinct,
+    abs, acos, acosh, array, array_append, array_compact, array_concat,
+    array_concat_distinct, array_concat_flat, array_dims, array_distance, array_distinct,
     array_element, array_except, array_has, array_has_all, array_has_any,
-    array_intersect, array_length, array_ndims, array_position, array_positions,
-    array_prepend, array_remove, array_remove_all, array_remove_n, array_repeat,
-    array_replace, array_replace_all, array_replace_n, array_slice, array_sort,
+    array_intersect, array_length, array_max, array_min, array_ndims, array_position,
+    array_position_from_end, array_positions, array_prepend, array_remove,
+    array_remove_all, array_remove_n, array_repeat, array_replace, array_replace_all,
+    array_replace_n, array_reverse, array_slice, array_sort, array_sublist, array_sum,
     array_to_string, arrow_typeof, ascii, asin, asinh, atan, atan2, atanh, bit_length,
     btrim, cardinality, cbrt, ceil, character_length, chr, coalesce, concat_expr,
     concat_ws_expr, cos, cosh, cot, current_date, current_time, date_bin, date_part,
@@ -477,13 +479,17 @@ impl From<&protobuf::ScalarFunction> for BuiltinScalarFunction {
             ScalarFunction::ToTimestamp => Self::ToTimestamp,
             ScalarFunction::ArrayAppend => Self::ArrayAppend,
             ScalarFunction::ArraySort => Self::ArraySort,
+            ScalarFunction::ArrayCompact => Self::ArrayCompact,
             ScalarFunction::ArrayConcat => Self::ArrayConcat,
+            ScalarFunction::ArrayConcatDistinct => Self::ArrayConcatDistinct,
+            ScalarFunction::ArrayConcatFlat => Self::ArrayConcatFlat,
             ScalarFunction::ArrayEmpty => Self::ArrayEmpty,
             ScalarFunction::ArrayExcept => Self::ArrayExcept,
             ScalarFunction::ArrayHasAll => Self::ArrayHasAll,
             ScalarFunction::ArrayHasAny => Self::ArrayHasAny,
             ScalarFunction::ArrayHas => Self::ArrayHas,
             ScalarFunction::ArrayDims => Self::ArrayDims,
+            ScalarFunction::ArrayDistance => Self::ArrayDistance,
             ScalarFunction::ArrayDistinct => Self::ArrayDistinct,
             ScalarFunction::ArrayElement => Self::ArrayElement,
             ScalarFunction::Flatten => Self::Flatten,
@@ -492,6 +498,7 @@ impl From<&protobuf::ScalarFunction> for BuiltinScalarFunction {
             ScalarFunction::ArrayPopFront => Self::ArrayPopFront,
             ScalarFunction::ArrayPopBack => Self::ArrayPopBack,
             ScalarFunction::ArrayPosition => Self::ArrayPosition,
+            ScalarFunction::ArrayPositionFromEnd => Self::ArrayPositionFromEnd,
             ScalarFunction::ArrayPositions => Self::ArrayPositions,
             ScalarFunction::ArrayPrepend => Self::ArrayPrepend,
             ScalarFunction::ArrayRepeat => Self::ArrayRepeat,
@@ -501,7 +508,12 @@ impl From<&protobuf::ScalarFunction> for BuiltinScalarFunction {
             ScalarFunction::ArrayReplace => Self::ArrayReplace,
             ScalarFunction::ArrayReplaceN => Self::ArrayReplaceN,
             ScalarFunction::ArrayReplaceAll => Self::ArrayReplaceAll,
+            ScalarFunction::ArrayReverse => Self::ArrayReverse,
             ScalarFunction::ArraySlice => Self::ArraySlice,
+            ScalarFunction::ArraySublist => Self::ArraySublist,
+            ScalarFunction::ArraySum => Self::ArraySum,
+            ScalarFunction::ArrayMax => Self::ArrayMax,
+            ScalarFunction::ArrayMin => Self::ArrayMin,
             ScalarFunction::ArrayToString => Self::ArrayToString,
             ScalarFunction::ArrayIntersect => Self::ArrayIntersect,
             ScalarFunction::ArrayUnion => Self::ArrayUnion,
@@ -1381,6 +1393,21 @@ pub fn parse_expr(
                         .map(|expr| parse_expr(expr, registry))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
+                ScalarFunction::ArrayCompact => {
+                    Ok(array_compact(parse_expr(&args[0], registry)?))
+                }
+                ScalarFunction::ArrayConcatDistinct => Ok(array_concat_distinct(
+                    args.to_owned()
+                        .iter()
+                        .map(|expr| parse_expr(expr, registry))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::ArrayConcatFlat => Ok(array_concat_flat(
+                    args.to_owned()
+                        .iter()
+                        .map(|expr| parse_expr(expr, registry))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
                 ScalarFunction::ArrayExcept => Ok(array_except(
                     parse_expr(&args[0], registry)?,
                     parse_expr(&args[1], registry)?,
@@ -1406,6 +1433,10 @@ pub fn parse_expr(
                     parse_expr(&args[1], registry)?,
                     parse_expr(&args[2], registry)?,
                 )),
+                ScalarFunction::ArrayPositionFromEnd => Ok(array_position_from_end(
+                    parse_expr(&args[0], registry)?,
+                    parse_expr(&args[1], registry)?,
+                )),
                 ScalarFunction::ArrayPositions => Ok(array_positions(
                     parse_expr(&args[0], registry)?,
                     parse_expr(&args[1], registry)?,
@@ -1443,11 +1474,28 @@ pub fn parse_expr(
                     parse_expr(&args[1], registry)?,
                     parse_expr(&args[2], registry)?,
                 )),
+                ScalarFunction::ArrayReverse => {
+                    Ok(array_reverse(parse_expr(&args[0], registry)?))
+                }
+                ScalarFunction::ArraySum => {
+                    Ok(array_sum(parse_expr(&args[0], registry)?))
+                }
+                ScalarFunction::ArrayMax => {
+                    Ok(array_max(parse_expr(&args[0], registry)?))
+                }
+                ScalarFunction::ArrayMin => {
+                    Ok(array_min(parse_expr(&args[0], registry)?))
+                }
                 ScalarFunction::ArraySlice => Ok(array_slice(
                     parse_expr(&args[0], registry)?,
                     parse_expr(&args[1], registry)?,
                     parse_expr(&args[2], registry)?,
                 )),
+                ScalarFunction::ArraySublist => Ok(array_sublist(
+                    parse_expr(&args[0], registry)?,
+                    parse_expr(&args[1], registry)?,
+                    parse_expr(&args[2], registry)?,
+                )),
                 ScalarFunction::ArrayToString => Ok(array_to_string(
                     parse_expr(&args[0], registry)?,
                     parse_expr(&args[1], registry)?,
@@ -1468,6 +1516,10 @@ pub fn parse_expr(
                 ScalarFunction::ArrayDims => {
                     Ok(array_dims(parse_expr(&args[0], registry)?))
                 }
+                ScalarFunction::ArrayDistance => Ok(array_distance(
+                    parse_expr(&args[0], registry)?,
+                    parse_expr(&args[1], registry)?,
+                )),
                 ScalarFunction::ArrayDistinct => {
                     Ok(array_distinct(parse_expr(&args[0], registry)?))
                 }